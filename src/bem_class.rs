@@ -0,0 +1,25 @@
+use crate::Case;
+use crate::Class;
+use crate::Convention;
+
+///
+/// A class which belongs to a BEM block.
+///
+/// This is implemented by `BaseClass`, `ElClass`, and `AttrClass`.
+/// It allows a class which has been passed in to be asked about
+/// which block it belongs to.
+///
+pub trait BemClass: Class {
+    type Convention: Convention;
+
+    /// Returns the name of the block this class belongs to.
+    fn block(&self) -> &str;
+
+    /// Returns the naming convention of the block this class belongs to.
+    fn convention(&self) -> Self::Convention;
+
+    /// Returns the case which names of this class, and the classes made from it, are printed in.
+    fn case(&self) -> Case {
+        Case::Preserve
+    }
+}
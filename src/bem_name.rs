@@ -0,0 +1,515 @@
+use crate::classes::{AnyBemClass, AttrClass, BaseClass};
+use crate::conventions::TwoDashes;
+use crate::Convention;
+use ::std::error::Error;
+use ::std::fmt;
+use ::std::str::FromStr;
+
+///
+/// A BEM class name, parsed into its block, elements, and modifiers.
+///
+/// This can parse a single name, such as `card__title--large`. It can
+/// also parse the full output of a class, such as
+/// `card__title card__title--large`, where each name shares the same
+/// block and elements.
+///
+/// ```
+/// use ::classnames::BemName;
+///
+/// let name: BemName = "card__title--size_large".parse().unwrap();
+/// assert_eq!(name.block(), "card");
+/// assert_eq!(name.elements(), ["title"]);
+/// assert_eq!(name.modifiers()[0].name(), "size");
+/// assert_eq!(name.modifiers()[0].value(), Some("large"));
+///
+/// // Prints "card__title card__title--size_large"
+/// let class = name.to_class();
+/// ```
+///
+/// Names are parsed and printed using the `TwoDashes` convention by
+/// default. Other conventions can be used with `parse_with`, or by
+/// naming the convention in the type.
+///
+/// ```
+/// use ::classnames::BemName;
+/// use ::classnames::conventions::Classic;
+///
+/// let name: BemName<Classic> = "card__title_size_large".parse().unwrap();
+/// assert_eq!(name.elements(), ["title"]);
+/// assert_eq!(name.modifiers()[0].value(), Some("large"));
+/// ```
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BemName<S = TwoDashes> {
+    block: String,
+    elements: Vec<String>,
+    modifiers: Vec<BemModifier>,
+    convention: S,
+}
+
+/// A modifier of a `BemName`. Such as `large`, or `size_large`.
+///
+/// A modifier is split into its key and value at the first value
+/// separator. So `is_active`, printed by `attr("is_active")`, is
+/// parsed as the key `is` with the value `active`. Both print the same.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BemModifier {
+    name: String,
+    value: Option<String>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ParseBemError {
+    /// There were no class names to parse.
+    Empty,
+    /// The block name is missing, such as in `__title`.
+    EmptyBlock,
+    /// An element name is missing, such as in `card____title`.
+    EmptyElement,
+    /// A modifier name is missing, such as in `card--`.
+    EmptyModifier,
+    /// A single class name has more than one modifier, such as `card--large--bold`.
+    MultipleModifiers,
+    /// The class names belong to different blocks or elements, such as `card grid--wide`.
+    MismatchedNames,
+    /// A name starts or ends with part of a separator, such as `card---large` or `card-`.
+    DanglingSeparator,
+}
+
+impl<S: Convention + 'static> BemName<S> {
+    /// Parses the name using the separators of the convention given.
+    pub fn parse_with(s: &str, convention: S) -> Result<Self, ParseBemError> {
+        let mut name: Option<BemName<S>> = None;
+
+        for token in s.split_whitespace() {
+            let parsed = parse_token(token, convention)?;
+
+            match &mut name {
+                None => name = Some(parsed),
+                Some(name) => {
+                    if name.block != parsed.block || name.elements != parsed.elements {
+                        return Err(ParseBemError::MismatchedNames);
+                    }
+
+                    name.modifiers.extend(parsed.modifiers);
+                }
+            }
+        }
+
+        name.ok_or(ParseBemError::Empty)
+    }
+
+    pub fn block(&self) -> &str {
+        &self.block
+    }
+
+    /// The path of elements, from the block down. This is empty for a block.
+    pub fn elements(&self) -> &[String] {
+        &self.elements
+    }
+
+    pub fn modifiers(&self) -> &[BemModifier] {
+        &self.modifiers
+    }
+
+    pub fn convention(&self) -> S {
+        self.convention
+    }
+
+    /// Builds the `BaseClass`, `ElClass`, and `AttrClass` chain for this name.
+    ///
+    /// Each element in the path is its own `ElClass`. As the depth of the
+    /// path is only known at runtime, each link is held in an `AnyBemClass`.
+    pub fn to_class(&self) -> AnyBemClass<S> {
+        let block = BaseClass::new(self.block.clone()).with_convention(self.convention);
+
+        let parent = self
+            .elements
+            .iter()
+            .fold(AnyBemClass::new(block), |parent, element| {
+                AnyBemClass::new(parent.el(element.clone()))
+            });
+
+        if self.modifiers.is_empty() {
+            return parent;
+        }
+
+        let attrs = self
+            .modifiers
+            .iter()
+            .fold(AttrClass::new(parent), |attrs, modifier| {
+                match &modifier.value {
+                    Some(value) => attrs.attr_value(modifier.name.clone(), value),
+                    None => attrs.attr(modifier.name.clone()),
+                }
+            });
+
+        AnyBemClass::new(attrs)
+    }
+
+    fn write_path(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.block)?;
+
+        for element in &self.elements {
+            write!(f, "{}", self.convention.element_separator())?;
+            self.convention.write_element(f, element)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BemModifier {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value of a key-value modifier. i.e. `large` in `size_large`.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+impl<S: Convention + Default + 'static> FromStr for BemName<S> {
+    type Err = ParseBemError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, S::default())
+    }
+}
+
+fn parse_token<S: Convention>(token: &str, convention: S) -> Result<BemName<S>, ParseBemError> {
+    let (path, modifier) = split_modifier(token, convention);
+
+    let mut path = path.split(convention.element_separator());
+    let block = path.next().unwrap_or_default();
+    if block.is_empty() {
+        return Err(ParseBemError::EmptyBlock);
+    }
+    check_dangling(block, convention)?;
+
+    let elements = path
+        .map(|element| match element {
+            "" => Err(ParseBemError::EmptyElement),
+            element => check_dangling(element, convention).map(|_| element.to_string()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let modifiers = match modifier {
+        None => Vec::new(),
+        Some("") => return Err(ParseBemError::EmptyModifier),
+        Some(modifier) => vec![parse_modifier(modifier, convention)?],
+    };
+
+    Ok(BemName {
+        block: block.to_string(),
+        elements,
+        modifiers,
+        convention,
+    })
+}
+
+/// Splits the token at the first modifier separator, into its path and modifier.
+///
+/// One separator can be part of the other, such as `_` and `__` in the
+/// classic style. Where both match, the longer separator is used.
+fn split_modifier<S: Convention>(token: &str, convention: S) -> (&str, Option<&str>) {
+    let element_separator = convention.element_separator();
+    let modifier_separator = convention.modifier_separator();
+
+    let mut rest = token;
+    while let Some(c) = rest.chars().next() {
+        let is_element = rest.starts_with(element_separator);
+        let is_modifier = rest.starts_with(modifier_separator);
+
+        if is_element && element_separator.len() > modifier_separator.len() {
+            rest = &rest[element_separator.len()..];
+        } else if is_modifier {
+            let i = token.len() - rest.len();
+            return (&token[..i], Some(&rest[modifier_separator.len()..]));
+        } else {
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    (token, None)
+}
+
+/// Rejects names which start or end with a separator character,
+/// as these are left over from a malformed separator.
+fn check_dangling<S: Convention>(name: &str, convention: S) -> Result<(), ParseBemError> {
+    let is_separator = |c: char| {
+        convention.element_separator().contains(c)
+            || convention.modifier_separator().contains(c)
+            || convention.value_separator().contains(c)
+    };
+
+    if name.starts_with(is_separator) || name.ends_with(is_separator) {
+        return Err(ParseBemError::DanglingSeparator);
+    }
+
+    Ok(())
+}
+
+fn parse_modifier<S: Convention>(
+    modifier: &str,
+    convention: S,
+) -> Result<BemModifier, ParseBemError> {
+    let (name, value) = match modifier.split_once(convention.value_separator()) {
+        Some((name, value)) if !name.is_empty() && !value.is_empty() => (name, Some(value)),
+        _ => (modifier, None),
+    };
+
+    // Where the separators are the same, as in the classic style,
+    // only the value can hold a second modifier.
+    let modifier_separator = convention.modifier_separator();
+    let rest = if modifier_separator == convention.value_separator() {
+        value
+    } else {
+        Some(modifier)
+    };
+
+    if rest.is_some_and(|rest| rest.contains(modifier_separator)) {
+        return Err(ParseBemError::MultipleModifiers);
+    }
+
+    check_dangling(name, convention)?;
+    if let Some(value) = value {
+        // Values may be negative numbers, such as `offset_-1`.
+        check_dangling(value.strip_prefix('-').unwrap_or(value), convention)?;
+    }
+
+    Ok(BemModifier {
+        name: name.to_string(),
+        value: value.map(str::to_string),
+    })
+}
+
+impl<S: Convention + 'static> fmt::Display for BemName<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_path(f)?;
+
+        for modifier in &self.modifiers {
+            write!(f, " ")?;
+            self.write_path(f)?;
+            write!(
+                f,
+                "{}{}",
+                self.convention.modifier_separator(),
+                modifier.name
+            )?;
+
+            if let Some(value) = &modifier.value {
+                write!(f, "{}{}", self.convention.value_separator(), value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ParseBemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::Empty => "no class name to parse",
+            Self::EmptyBlock => "class name is missing a block",
+            Self::EmptyElement => "class name has an empty element",
+            Self::EmptyModifier => "class name has an empty modifier",
+            Self::MultipleModifiers => "class name has more than one modifier",
+            Self::MismatchedNames => "class names belong to different blocks or elements",
+            Self::DanglingSeparator => "class name has a dangling separator",
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+impl Error for ParseBemError {}
+
+#[cfg(test)]
+mod from_str {
+    use super::*;
+    use crate::conventions::{Classic, ReactStyle, Suit};
+
+    #[test]
+    fn it_should_parse_blocks() {
+        let name: BemName = "card".parse().unwrap();
+        assert_eq!(name.block(), "card");
+        assert!(name.elements().is_empty());
+        assert!(name.modifiers().is_empty());
+    }
+
+    #[test]
+    fn it_should_parse_element_paths_and_modifiers() {
+        let name: BemName = "card__title__icon--large".parse().unwrap();
+        assert_eq!(name.block(), "card");
+        assert_eq!(name.elements(), ["title", "icon"]);
+        assert_eq!(name.modifiers()[0].name(), "large");
+        assert_eq!(name.modifiers()[0].value(), None);
+    }
+
+    #[test]
+    fn it_should_parse_printed_classes() {
+        let name: BemName = "card__title card__title--large card__title--size_2"
+            .parse()
+            .unwrap();
+        assert_eq!(name.elements(), ["title"]);
+        assert_eq!(name.modifiers().len(), 2);
+        assert_eq!(name.modifiers()[1].value(), Some("2"));
+    }
+
+    #[test]
+    fn it_should_parse_classic_names() {
+        let name: BemName<Classic> = "card__title__icon_size_2".parse().unwrap();
+        assert_eq!(name.elements(), ["title", "icon"]);
+        assert_eq!(name.modifiers()[0].name(), "size");
+        assert_eq!(name.modifiers()[0].value(), Some("2"));
+        assert_eq!(
+            name.to_string(),
+            "card__title__icon card__title__icon_size_2"
+        );
+    }
+
+    #[test]
+    fn it_should_parse_react_style_names() {
+        let name = BemName::parse_with("Card-title Card-title_large", ReactStyle).unwrap();
+        assert_eq!(name.block(), "Card");
+        assert_eq!(name.elements(), ["title"]);
+        assert_eq!(name.modifiers()[0].name(), "large");
+    }
+
+    #[test]
+    fn it_should_parse_suit_names() {
+        let name: BemName<Suit> = "MyComponent-descendentName--size-large".parse().unwrap();
+        assert_eq!(name.block(), "MyComponent");
+        assert_eq!(name.elements(), ["descendentName"]);
+        assert_eq!(name.modifiers()[0].name(), "size");
+        assert_eq!(name.modifiers()[0].value(), Some("large"));
+    }
+
+    #[test]
+    fn it_should_return_errors_for_malformed_names_of_other_conventions() {
+        assert_eq!(
+            "Card---large".parse::<BemName<Suit>>(),
+            Err(ParseBemError::DanglingSeparator)
+        );
+        assert_eq!(
+            "Card--large--bold".parse::<BemName<Suit>>(),
+            Err(ParseBemError::MultipleModifiers)
+        );
+        assert_eq!(
+            "card____title".parse::<BemName<Classic>>(),
+            Err(ParseBemError::EmptyElement)
+        );
+    }
+
+    #[test]
+    fn it_should_return_errors_for_malformed_names() {
+        assert_eq!("  ".parse::<BemName>(), Err(ParseBemError::Empty));
+        assert_eq!("__title".parse::<BemName>(), Err(ParseBemError::EmptyBlock));
+        assert_eq!(
+            "card____title".parse::<BemName>(),
+            Err(ParseBemError::EmptyElement)
+        );
+        assert_eq!(
+            "card--".parse::<BemName>(),
+            Err(ParseBemError::EmptyModifier)
+        );
+        assert_eq!(
+            "card--large--bold".parse::<BemName>(),
+            Err(ParseBemError::MultipleModifiers)
+        );
+        assert_eq!(
+            "card grid--wide".parse::<BemName>(),
+            Err(ParseBemError::MismatchedNames)
+        );
+        assert_eq!(
+            "card---x".parse::<BemName>(),
+            Err(ParseBemError::DanglingSeparator)
+        );
+        assert_eq!(
+            "card-".parse::<BemName>(),
+            Err(ParseBemError::DanglingSeparator)
+        );
+        assert_eq!(
+            "card___title".parse::<BemName>(),
+            Err(ParseBemError::DanglingSeparator)
+        );
+        assert_eq!(
+            "card--size_".parse::<BemName>(),
+            Err(ParseBemError::DanglingSeparator)
+        );
+    }
+}
+
+#[cfg(test)]
+mod to_class {
+    use super::*;
+    use crate::classes::ElClass;
+    use crate::conventions::Suit;
+    use crate::BemClass;
+
+    #[test]
+    fn it_should_parse_negative_values() {
+        let chain = BaseClass::new("card").attr_value("offset", -1);
+        let name: BemName = chain.to_string().parse().unwrap();
+        assert_eq!(name.modifiers()[0].value(), Some("-1"));
+        assert_eq!(name.to_string(), chain.to_string());
+    }
+
+    #[test]
+    fn it_should_parse_modifiers_holding_the_value_separator_as_key_values() {
+        let chain = BaseClass::new("card").attr("is_active");
+        let name: BemName = chain.to_string().parse().unwrap();
+        assert_eq!(name.modifiers()[0].name(), "is");
+        assert_eq!(name.modifiers()[0].value(), Some("active"));
+        assert_eq!(name.to_string(), chain.to_string());
+        assert_eq!(name.to_class().to_string(), chain.to_string());
+    }
+
+    #[test]
+    fn it_should_print_the_same_as_the_chain() {
+        let chain = BaseClass::new("card")
+            .el("title")
+            .el("icon")
+            .attr("large")
+            .attr_value("size", 2);
+        let name: BemName = chain.to_string().parse().unwrap();
+        assert_eq!(name.to_class().to_string(), chain.to_string());
+        assert_eq!(name.to_string(), chain.to_string());
+    }
+
+    #[test]
+    fn it_should_build_a_chain_of_elements() {
+        let name: BemName = "card__title__icon--large".parse().unwrap();
+        let class = name.to_class();
+        assert_eq!(class.block(), "card");
+
+        let attrs = class.downcast_ref::<AttrClass<AnyBemClass>>().unwrap();
+        assert!(attrs.has_modifier("large"));
+
+        let icon = attrs
+            .parent()
+            .downcast_ref::<ElClass<AnyBemClass, String>>();
+        assert_eq!(icon.unwrap().element().unwrap(), "icon");
+
+        let title = icon
+            .unwrap()
+            .parent()
+            .downcast_ref::<ElClass<AnyBemClass, String>>();
+        assert_eq!(title.unwrap().element().unwrap(), "title");
+    }
+
+    #[test]
+    fn it_should_print_using_the_convention() {
+        let chain = BaseClass::new("MyComponent")
+            .with_convention(Suit)
+            .el("descendentName")
+            .attr("large")
+            .attr_value("size", 2);
+        let name: BemName<Suit> = chain.to_string().parse().unwrap();
+        assert_eq!(name.to_class().to_string(), chain.to_string());
+        assert_eq!(name.to_string(), chain.to_string());
+        assert_eq!(name.to_class().convention(), Suit);
+    }
+}
@@ -0,0 +1,299 @@
+use ::std::fmt;
+use ::std::fmt::Write;
+
+///
+/// The case to print names in, such as `PrimaryButton` as `primary-button`.
+///
+/// This is for names which come from Rust, such as enum variants
+/// and struct fields. Names are converted as they are printed,
+/// and names which already conform are left unchanged.
+///
+/// ```
+/// use ::classnames::{classname, Case};
+///
+/// // Prints "primary-button primary-button--is-active"
+/// let button = classname("PrimaryButton").with_case(Case::Kebab).attr("is_active");
+/// ```
+///
+/// A new word starts after a dash, an underscore, or whitespace,
+/// and at an uppercase letter following a lowercase letter or digit.
+/// In a run of capitals, the last starts a new word when a lowercase
+/// letter follows it. So `HTMLParser` is printed as `html-parser` in
+/// kebab case, and as `htmlParser` in camel case.
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Case {
+    /// Names are printed as they are given.
+    #[default]
+    Preserve,
+    /// i.e. `primary-button`
+    Kebab,
+    /// i.e. `primary_button`
+    Snake,
+    /// i.e. `primaryButton`
+    Camel,
+}
+
+impl Case {
+    /// Returns true if the name would be printed unchanged in this case.
+    pub fn is_conforming(self, name: &str) -> bool {
+        match self {
+            Case::Preserve => true,
+            Case::Kebab => is_separated_case(name, '-', '_'),
+            Case::Snake => is_separated_case(name, '_', '-'),
+            Case::Camel => is_camel_case(name),
+        }
+    }
+
+    /// Wraps the name, so it is converted into this case when printed.
+    pub(crate) fn apply<D: fmt::Display + ?Sized>(self, name: &D) -> Cased<'_, D> {
+        Cased { case: self, name }
+    }
+}
+
+fn is_word_separator(c: char) -> bool {
+    c == '-' || c == '_' || c.is_whitespace()
+}
+
+fn is_separated_case(name: &str, separator: char, other: char) -> bool {
+    let mut previous = None;
+    for c in name.chars() {
+        if c.is_uppercase() || c.is_whitespace() || c == other {
+            return false;
+        }
+
+        if c == separator && (previous.is_none() || previous == Some(separator)) {
+            return false;
+        }
+
+        previous = Some(c);
+    }
+
+    previous != Some(separator)
+}
+
+/// Camel case starts lowercase, and has no separators or runs of capitals.
+fn is_camel_case(name: &str) -> bool {
+    let mut was_uppercase = true;
+    for c in name.chars() {
+        if is_word_separator(c) || (c.is_uppercase() && was_uppercase) {
+            return false;
+        }
+
+        was_uppercase = c.is_uppercase();
+    }
+
+    true
+}
+
+/// A name which is converted into a case as it is printed.
+pub(crate) struct Cased<'a, D: ?Sized> {
+    case: Case,
+    name: &'a D,
+}
+
+impl<'a, D: fmt::Display + ?Sized> fmt::Display for Cased<'a, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.case {
+            Case::Preserve => write!(f, "{}", self.name),
+            case => {
+                let mut writer = CaseWriter::new(f, case);
+                write!(writer, "{}", self.name)?;
+                writer.finish()
+            }
+        }
+    }
+}
+
+///
+/// Wraps a writer, and converts what is written through it into a case.
+///
+/// Leading, trailing, and repeated separators are removed. Whether a
+/// capital starts a new word depends on the letter after it, so each
+/// letter is held back until the next arrives. Call `finish` to write
+/// the last letter.
+///
+pub(crate) struct CaseWriter<W> {
+    inner: W,
+    case: Case,
+    has_written: bool,
+    is_word_next: bool,
+    /// The letter before the pending letter, in the same word.
+    previous: Option<char>,
+    /// A letter not yet written, and if it starts a word so far.
+    pending: Option<(char, bool)>,
+}
+
+impl<W: fmt::Write> CaseWriter<W> {
+    pub(crate) fn new(inner: W, case: Case) -> Self {
+        Self {
+            inner,
+            case,
+            has_written: false,
+            is_word_next: false,
+            previous: None,
+            pending: None,
+        }
+    }
+
+    /// Writes the letter held back, if there is one.
+    pub(crate) fn finish(&mut self) -> fmt::Result {
+        if let Some((c, is_word_start)) = self.pending.take() {
+            self.write_letter(c, is_word_start)?;
+        }
+
+        Ok(())
+    }
+
+    fn push(&mut self, c: char) -> fmt::Result {
+        if is_word_separator(c) {
+            self.finish()?;
+            self.previous = None;
+            self.is_word_next = true;
+            return Ok(());
+        }
+
+        if let Some((pending, is_word_start)) = self.pending.take() {
+            // i.e. the `P` in `HTMLParser`.
+            let is_end_of_capitals = self.previous.is_some_and(char::is_uppercase)
+                && pending.is_uppercase()
+                && c.is_lowercase();
+
+            self.write_letter(pending, is_word_start || is_end_of_capitals)?;
+            self.previous = Some(pending);
+        }
+
+        let is_after_lowercase = self
+            .previous
+            .is_some_and(|previous| previous.is_lowercase() || previous.is_numeric());
+        let is_word_start = self.is_word_next || (c.is_uppercase() && is_after_lowercase);
+
+        self.is_word_next = false;
+        self.pending = Some((c, is_word_start));
+        Ok(())
+    }
+
+    fn write_letter(&mut self, c: char, is_word_start: bool) -> fmt::Result {
+        let is_first = !self.has_written;
+        self.has_written = true;
+
+        match self.case {
+            Case::Preserve => self.inner.write_char(c),
+            Case::Kebab | Case::Snake => {
+                if is_word_start && !is_first {
+                    let separator = if self.case == Case::Kebab { '-' } else { '_' };
+                    self.inner.write_char(separator)?;
+                }
+
+                write!(self.inner, "{}", c.to_lowercase())
+            }
+            Case::Camel => {
+                if is_word_start && !is_first {
+                    write!(self.inner, "{}", c.to_uppercase())
+                } else {
+                    write!(self.inner, "{}", c.to_lowercase())
+                }
+            }
+        }
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for CaseWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.push(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod case_writer {
+    use super::*;
+
+    fn convert(s: &str, case: Case) -> String {
+        let mut output = String::new();
+        let mut writer = CaseWriter::new(&mut output, case);
+        writer.write_str(s).unwrap();
+        writer.finish().unwrap();
+        output
+    }
+
+    #[test]
+    fn it_should_convert_to_kebab_case() {
+        assert_eq!(convert("PrimaryButton", Case::Kebab), "primary-button");
+        assert_eq!(convert("is_active", Case::Kebab), "is-active");
+        assert_eq!(convert("item2Large", Case::Kebab), "item2-large");
+        assert_eq!(convert("__title  Text_", Case::Kebab), "title-text");
+        assert_eq!(convert("primary-button", Case::Kebab), "primary-button");
+    }
+
+    #[test]
+    fn it_should_convert_to_camel_case() {
+        assert_eq!(convert("descendent-name", Case::Camel), "descendentName");
+        assert_eq!(convert("descendent_name", Case::Camel), "descendentName");
+        assert_eq!(convert("DescendentName", Case::Camel), "descendentName");
+        assert_eq!(convert("-title-", Case::Camel), "title");
+        assert_eq!(convert("descendentName", Case::Camel), "descendentName");
+    }
+
+    #[test]
+    fn it_should_split_acronyms_and_capitals() {
+        assert_eq!(convert("HTMLParser", Case::Kebab), "html-parser");
+        assert_eq!(convert("HTMLParser", Case::Camel), "htmlParser");
+        assert_eq!(convert("URLInput", Case::Kebab), "url-input");
+        assert_eq!(convert("URLInput", Case::Camel), "urlInput");
+        assert_eq!(convert("PRIMARY_BUTTON", Case::Camel), "primaryButton");
+        assert_eq!(convert("PRIMARY_BUTTON", Case::Snake), "primary_button");
+        assert_eq!(convert("parseHTML", Case::Kebab), "parse-html");
+    }
+
+    #[test]
+    fn it_should_keep_words_split_across_writes() {
+        let mut output = String::new();
+        let mut writer = CaseWriter::new(&mut output, Case::Snake);
+        writer.write_str("Primary").unwrap();
+        writer.write_str("HTMLB").unwrap();
+        writer.write_str("utton-").unwrap();
+        writer.write_str("-large").unwrap();
+        writer.finish().unwrap();
+        assert_eq!(output, "primary_html_button_large");
+    }
+}
+
+#[cfg(test)]
+mod is_conforming {
+    use super::*;
+
+    #[test]
+    fn it_should_match_the_converted_name() {
+        let names = [
+            "primary-button",
+            "primary_button",
+            "primaryButton",
+            "PrimaryButton",
+            "-title",
+            "title--large",
+            "item2",
+            "HTMLParser",
+            "htmlParser",
+            "myID",
+            "PRIMARY_BUTTON",
+            "",
+        ];
+
+        for &case in &[Case::Preserve, Case::Kebab, Case::Snake, Case::Camel] {
+            for name in &names {
+                let is_unchanged = case.apply(*name).to_string() == *name;
+                assert_eq!(
+                    case.is_conforming(name),
+                    is_unchanged,
+                    "{:?} {}",
+                    case,
+                    name
+                );
+            }
+        }
+    }
+}
@@ -1,9 +1,131 @@
-use crate::classes::BaseClass;
+use crate::classes::{AnyClass, BaseClass, DedupClass, OptionClass};
+use crate::render;
+use crate::Tokens;
+use ::std::borrow::Cow;
+use ::std::collections::BTreeSet;
 use ::std::fmt;
+use ::std::io;
 
-pub trait Class: Sized + Clone + PartialEq + fmt::Display {}
+pub trait Class: Sized + Clone + PartialEq + fmt::Display {
+    /// Returns an iterator over each of the class names, one at a time.
+    ///
+    /// By default this will print the class, and split it on whitespace.
+    fn tokens(&self) -> Tokens<'_> {
+        let tokens = self
+            .to_string()
+            .split_whitespace()
+            .map(|token| Cow::Owned(token.to_string()))
+            .collect::<Vec<_>>();
+
+        Tokens::new(tokens.into_iter())
+    }
+
+    /// Writes the class into the given writer, without building a `String`.
+    fn write_to<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        write!(writer, "{}", self)
+    }
+
+    /// Writes the class into the given `io::Write`, such as a response buffer.
+    fn write_io<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    /// Returns the exact length, in bytes, of the class once printed.
+    ///
+    /// Useful for reserving space in a buffer before writing to it.
+    fn rendered_len(&self) -> usize {
+        render::rendered_len(self)
+    }
+
+    /// Returns this class if `is_set` is true, and an empty class if false.
+    fn when(self, is_set: bool) -> OptionClass<Self> {
+        OptionClass::new(if is_set { Some(self) } else { None })
+    }
+
+    /// Boxes this class into an `AnyClass`, erasing its type.
+    fn into_any(self) -> AnyClass
+    where
+        Self: 'static,
+    {
+        AnyClass::new(self)
+    }
+
+    /// Wraps this class, so each class token is only printed once.
+    fn dedup(self) -> DedupClass<Self> {
+        DedupClass::new(self)
+    }
+}
+
+impl Class for &str {
+    fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(self.split_whitespace().map(Cow::Borrowed))
+    }
+}
+
+impl Class for String {
+    fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(self.split_whitespace().map(Cow::Borrowed))
+    }
+}
+
+/// Returns true if both classes print the same set of class names.
+///
+/// Unlike `==`, this ignores the order and structure of the classes.
+/// So `a + b` is the same as `b + a`, and `card card` as `card`.
+pub fn same_classes<A: Class, B: Class>(a: &A, b: &B) -> bool {
+    a.tokens().collect::<BTreeSet<_>>() == b.tokens().collect::<BTreeSet<_>>()
+}
 
 /// Creates a new class.
-pub fn classname(name: &'static str) -> BaseClass {
+///
+/// The name can be a `&'static str`, a borrowed `&str`, a `String`,
+/// or a `Cow<str>`. String literals are stored without allocating.
+pub fn classname<C: AsRef<str> + Clone>(name: C) -> BaseClass<C> {
     BaseClass::new(name)
 }
+
+#[cfg(test)]
+mod same_classes {
+    use crate::*;
+
+    #[test]
+    fn it_should_ignore_order_of_classes() {
+        let a = classname("card") + "grid";
+        let b = classname("grid") + "card";
+        assert_ne!(a, b);
+        assert!(same_classes(&a, &b));
+    }
+
+    #[test]
+    fn it_should_compare_different_class_types() {
+        let class = classname("card").attr("large");
+        assert!(same_classes(&class, &"card--large card card"));
+        assert!(!same_classes(&class, &"card"));
+    }
+}
+
+#[cfg(test)]
+mod write_to {
+    use crate::*;
+
+    #[test]
+    fn it_should_write_into_fmt_writers() {
+        let mut output = String::from("class=\"");
+        let class = classname("card").attr("large") + "bold";
+        class.write_to(&mut output).unwrap();
+        assert_eq!(output, "class=\"card card--large bold");
+    }
+
+    #[test]
+    fn it_should_write_into_io_writers() {
+        let mut output: Vec<u8> = Vec::new();
+        classname("card").el("title").write_io(&mut output).unwrap();
+        assert_eq!(output, b"card__title");
+    }
+
+    #[test]
+    fn it_should_return_exact_rendered_len() {
+        let class = classname("card").attr("large") + None::<classes::BaseClass> + "bold";
+        assert_eq!(class.rendered_len(), class.to_string().len());
+    }
+}
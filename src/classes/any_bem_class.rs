@@ -0,0 +1,207 @@
+use crate::classes::{AnyClass, AttrClass, DuoClass, ElClass, MixClass, OptionClass};
+use crate::conventions::TwoDashes;
+use crate::render::render;
+use crate::BemClass;
+use crate::Case;
+use crate::Class;
+use crate::Convention;
+use crate::IntoModifier;
+use crate::ModifierGroup;
+use crate::Tokens;
+use ::std::borrow::Cow;
+use ::std::convert::From;
+use ::std::fmt;
+use ::std::ops::Add;
+
+///
+/// A boxed BEM class, which can hold any class of the same naming convention.
+///
+/// Unlike `AnyClass`, this keeps the block and convention of the class
+/// held. So elements and modifiers can still be made from it, such as
+/// when the depth of a chain of elements is only known at runtime.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AnyBemClass<S: Convention = TwoDashes> {
+    class: AnyClass,
+    block: String,
+    convention: S,
+    case: Case,
+}
+
+impl<S: Convention + 'static> Class for AnyBemClass<S> {
+    fn tokens(&self) -> Tokens<'_> {
+        self.class.tokens()
+    }
+}
+
+impl<S: Convention + 'static> BemClass for AnyBemClass<S> {
+    type Convention = S;
+
+    fn block(&self) -> &str {
+        &self.block
+    }
+
+    fn convention(&self) -> S {
+        self.convention
+    }
+
+    fn case(&self) -> Case {
+        self.case
+    }
+}
+
+impl<S: Convention + 'static> AnyBemClass<S> {
+    pub fn new<C: BemClass<Convention = S> + 'static>(class: C) -> Self {
+        Self {
+            block: class.block().to_string(),
+            convention: class.convention(),
+            case: class.case(),
+            class: AnyClass::new(class),
+        }
+    }
+
+    /// Returns the class held, if it is of the type given.
+    pub fn downcast_ref<C: 'static>(&self) -> Option<&C> {
+        self.class.downcast_ref()
+    }
+
+    pub fn el<E: fmt::Display + Clone>(self, class: E) -> ElClass<Self, E> {
+        ElClass::new(self, class)
+    }
+
+    pub fn maybe_el<E: fmt::Display + Clone>(self, class: E, is_set: bool) -> ElClass<Self, E> {
+        ElClass::new_maybe(self, if is_set { Some(class) } else { None })
+    }
+
+    pub fn attr<A: IntoModifier>(self, attr: A) -> AttrClass<Self> {
+        AttrClass::new(self).attr(attr)
+    }
+
+    pub fn maybe_attr<A: IntoModifier>(self, attr: A, is_set: bool) -> AttrClass<Self> {
+        AttrClass::new(self).maybe_attr(attr, is_set)
+    }
+
+    pub fn attr_opt<A: IntoModifier>(self, attr: Option<A>) -> AttrClass<Self> {
+        AttrClass::new(self).attr_opt(attr)
+    }
+
+    pub fn attrs<I, A>(self, attrs: I) -> AttrClass<Self>
+    where
+        I: IntoIterator<Item = (A, bool)>,
+        A: IntoModifier,
+    {
+        AttrClass::new(self).attrs(attrs)
+    }
+
+    pub fn attr_value<K, V>(self, key: K, value: V) -> AttrClass<Self>
+    where
+        K: IntoModifier,
+        V: fmt::Display,
+    {
+        AttrClass::new(self).attr_value(key, value)
+    }
+
+    pub fn group(self, group: ModifierGroup) -> AttrClass<Self> {
+        AttrClass::new(self).group(group)
+    }
+
+    /// Sets a state class, such as `is-active`, which is printed without the block.
+    pub fn state<A: IntoModifier>(self, state: A) -> AttrClass<Self> {
+        AttrClass::new(self).state(state)
+    }
+
+    pub fn maybe_state<A: IntoModifier>(self, state: A, is_set: bool) -> AttrClass<Self> {
+        AttrClass::new(self).maybe_state(state, is_set)
+    }
+
+    /// Mixes another block into this class.
+    pub fn mix<M: Class>(self, block: M) -> MixClass<Self, M> {
+        MixClass::new(self, block)
+    }
+}
+
+impl<S, O> Add<O> for AnyBemClass<S>
+where
+    S: Convention + 'static,
+    O: Class,
+{
+    type Output = DuoClass<Self, O>;
+
+    fn add(self, other: O) -> Self::Output {
+        DuoClass::new(self, other)
+    }
+}
+
+impl<S, O> Add<Option<O>> for AnyBemClass<S>
+where
+    S: Convention + 'static,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
+    }
+}
+
+impl<S, O> Add<(O, bool)> for AnyBemClass<S>
+where
+    S: Convention + 'static,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
+    }
+}
+
+impl<S: Convention> fmt::Display for AnyBemClass<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.class)
+    }
+}
+
+impl<'a, S: Convention> From<AnyBemClass<S>> for Cow<'a, str> {
+    fn from(class: AnyBemClass<S>) -> Self {
+        render(&class).into()
+    }
+}
+
+impl<S: Convention> From<AnyBemClass<S>> for String {
+    fn from(class: AnyBemClass<S>) -> Self {
+        render(&class)
+    }
+}
+
+#[cfg(test)]
+mod new {
+    use super::*;
+    use crate::classname;
+
+    fn title_class(depth: usize) -> AnyBemClass {
+        (0..depth).fold(AnyBemClass::new(classname("card")), |class, i| {
+            AnyBemClass::new(class.el(format!("level-{}", i)))
+        })
+    }
+
+    #[test]
+    fn it_should_make_elements_and_modifiers_from_the_class_held() {
+        let class = title_class(2).attr("large");
+        assert_eq!(
+            class.to_string(),
+            "card__level-0__level-1 card__level-0__level-1--large"
+        );
+        assert_eq!(class.block(), "card");
+    }
+
+    #[test]
+    fn it_should_compare_and_downcast_the_class_held() {
+        assert_eq!(title_class(1), title_class(1));
+        assert_ne!(title_class(1), title_class(2));
+
+        let class = title_class(1);
+        let el = class.downcast_ref::<ElClass<AnyBemClass, String>>();
+        assert_eq!(el.and_then(|el| el.element()).unwrap(), "level-0");
+    }
+}
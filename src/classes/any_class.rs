@@ -0,0 +1,163 @@
+use crate::classes::{DuoClass, OptionClass};
+use crate::render::render;
+use crate::Class;
+use crate::DynClass;
+use crate::Tokens;
+use ::std::borrow::Cow;
+use ::std::convert::From;
+use ::std::fmt;
+use ::std::hash::{Hash, Hasher};
+use ::std::ops::Add;
+
+///
+/// A boxed class, which can hold any other class.
+///
+/// This allows different classes to be stored together in a `Vec`,
+/// held in a struct without generics, or returned from different
+/// match arms.
+///
+pub struct AnyClass {
+    class: Box<dyn DynClass>,
+}
+
+impl Class for AnyClass {
+    fn tokens(&self) -> Tokens<'_> {
+        self.class.dyn_tokens()
+    }
+}
+
+impl AnyClass {
+    pub fn new<C: Class + 'static>(class: C) -> Self {
+        Self {
+            class: Box::new(class),
+        }
+    }
+
+    /// Returns the class held, if it is of the type given.
+    pub fn downcast_ref<C: 'static>(&self) -> Option<&C> {
+        self.class.as_any().downcast_ref()
+    }
+}
+
+impl Clone for AnyClass {
+    fn clone(&self) -> Self {
+        Self {
+            class: self.class.clone_box(),
+        }
+    }
+}
+
+impl PartialEq for AnyClass {
+    fn eq(&self, other: &Self) -> bool {
+        self.class.dyn_eq(other.class.as_ref())
+    }
+}
+
+impl Eq for AnyClass {}
+
+/// Hashes the class names printed, as the class held may not implement `Hash`.
+impl Hash for AnyClass {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for token in self.tokens() {
+            token.hash(state);
+        }
+    }
+}
+
+impl fmt::Debug for AnyClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnyClass").field(&render(self)).finish()
+    }
+}
+
+impl<O> Add<O> for AnyClass
+where
+    O: Class,
+{
+    type Output = DuoClass<Self, O>;
+
+    fn add(self, other: O) -> Self::Output {
+        DuoClass::new(self, other)
+    }
+}
+
+impl<O> Add<Option<O>> for AnyClass
+where
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
+    }
+}
+
+impl<O> Add<(O, bool)> for AnyClass
+where
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
+    }
+}
+
+impl fmt::Display for AnyClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.class)
+    }
+}
+
+impl<'a> From<AnyClass> for Cow<'a, str> {
+    fn from(class: AnyClass) -> Self {
+        render(&class).into()
+    }
+}
+
+impl From<AnyClass> for String {
+    fn from(class: AnyClass) -> Self {
+        render(&class)
+    }
+}
+
+#[cfg(test)]
+mod into_any {
+    use super::*;
+    use crate::classname;
+
+    fn title_class(is_large: bool) -> AnyClass {
+        if is_large {
+            classname("title").attr("large").into_any()
+        } else {
+            classname("card").el("title").into_any()
+        }
+    }
+
+    #[test]
+    fn it_should_hold_different_classes_together() {
+        let classes = [title_class(true), title_class(false)];
+        let printed = classes.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        assert_eq!(printed, vec!["title title--large", "card__title"]);
+    }
+
+    #[test]
+    fn it_should_compose_with_other_classes() {
+        let class = classname("page") + title_class(false) + "bold";
+        assert_eq!(class.to_string(), "page card__title bold");
+    }
+
+    #[test]
+    fn it_should_compare_the_classes_held() {
+        assert_eq!(title_class(true), title_class(true));
+        assert_ne!(title_class(true), title_class(false));
+        assert_ne!(AnyClass::new(classname("card")), AnyClass::new("card"));
+    }
+
+    #[test]
+    fn it_should_downcast_the_class_held() {
+        let class = AnyClass::new(classname("card"));
+        assert_eq!(class.downcast_ref(), Some(&classname("card")));
+        assert_eq!(class.downcast_ref::<&str>(), None);
+    }
+}
@@ -1,52 +1,319 @@
 use crate::classes::DuoClass;
 use crate::classes::ElClass;
 use crate::classes::OptionClass;
+use crate::render::render;
+use crate::token_writer::TokenWriter;
+use crate::BemClass;
+use crate::Case;
 use crate::Class;
+use crate::Convention;
+use crate::IntoModifier;
+use crate::ModifierGroup;
+use crate::Tokens;
 use ::smallvec::SmallVec;
 use ::std::borrow::Cow;
 use ::std::convert::From;
 use ::std::fmt;
+use ::std::fmt::Write;
 use ::std::ops::Add;
 
 const ATTR_SMALL_VEC_SIZE: usize = 3;
+const GROUP_SMALL_VEC_SIZE: usize = 1;
+const STATE_SMALL_VEC_SIZE: usize = 1;
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct AttrClass<N> {
     parent: N,
-    attrs: SmallVec<[&'static str; ATTR_SMALL_VEC_SIZE]>,
+    attrs: SmallVec<[Attr; ATTR_SMALL_VEC_SIZE]>,
+    groups: SmallVec<[ModifierGroup; GROUP_SMALL_VEC_SIZE]>,
+    states: SmallVec<[Cow<'static, str>; STATE_SMALL_VEC_SIZE]>,
+    value_separator: Option<&'static str>,
+    is_modifiers_only: bool,
+    case: Option<Case>,
 }
 
-impl<N> Class for AttrClass<N> where N: fmt::Display + Sized + PartialEq + Clone {}
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+struct Attr {
+    name: Cow<'static, str>,
+    value: Option<Cow<'static, str>>,
+}
+
+impl Attr {
+    /// Returns true if this is a modifier without a value, which is in the group.
+    fn is_in(&self, group: ModifierGroup) -> bool {
+        self.value.is_none() && group.contains(&self.name)
+    }
+}
+
+impl<N> Class for AttrClass<N>
+where
+    N: BemClass,
+{
+    fn tokens(&self) -> Tokens<'_> {
+        let attrs = self.attrs.iter().map(move |attr| {
+            let mut token = String::new();
+            // Writing to a `String` cannot fail.
+            let _ = self.write_attr(&mut token, attr);
+            Cow::Owned(token)
+        });
+
+        let states = self.states.iter().map(move |state| {
+            let prefix = self.parent.convention().state_prefix();
+            Cow::Owned(format!("{}{}", prefix, self.case().apply(state)))
+        });
+
+        let parent = if self.is_parent_printed() {
+            Some(self.parent.tokens())
+        } else {
+            None
+        };
+
+        Tokens::new(parent.into_iter().flatten().chain(attrs).chain(states))
+    }
+}
+
+impl<N> BemClass for AttrClass<N>
+where
+    N: BemClass,
+{
+    type Convention = N::Convention;
+
+    fn block(&self) -> &str {
+        self.parent.block()
+    }
+
+    fn convention(&self) -> Self::Convention {
+        self.parent.convention()
+    }
+
+    fn case(&self) -> Case {
+        self.case.unwrap_or_else(|| self.parent.case())
+    }
+}
+
+impl<N> AttrClass<N> {
+    /// Returns the class these modifiers are set on.
+    pub fn parent(&self) -> &N {
+        &self.parent
+    }
+
+    /// Returns the names of the modifiers set, in the order they were set.
+    ///
+    /// For key-value modifiers, this is the key.
+    pub fn modifiers(&self) -> impl Iterator<Item = &str> {
+        self.attrs.iter().map(|attr| attr.name.as_ref())
+    }
+
+    pub fn has_modifier(&self, name: &str) -> bool {
+        self.modifiers().any(|modifier| modifier == name)
+    }
+
+    /// Returns the names of the states set, without their prefix.
+    pub fn states(&self) -> impl Iterator<Item = &str> {
+        self.states.iter().map(|state| state.as_ref())
+    }
+
+    pub fn has_state(&self, name: &str) -> bool {
+        self.states().any(|state| state == name)
+    }
+
+    /// Returns the value of a key-value modifier, if it is set.
+    pub fn modifier_value(&self, key: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .filter(|attr| attr.name == key)
+            .find_map(|attr| attr.value.as_deref())
+    }
+}
+
+impl<N: BemClass> AttrClass<N> {
+    fn is_parent_printed(&self) -> bool {
+        let is_modifiers_only =
+            self.is_modifiers_only || self.parent.convention().is_modifiers_only();
+
+        self.attrs.is_empty() || !is_modifiers_only
+    }
+
+    fn write_attr<W: fmt::Write>(&self, w: &mut W, attr: &Attr) -> fmt::Result {
+        let convention = self.parent.convention();
+        let case = self.case();
+        write!(
+            w,
+            "{}{}{}",
+            self.parent,
+            convention.modifier_separator(),
+            case.apply(&attr.name)
+        )?;
+
+        if let Some(value) = &attr.value {
+            let separator = self
+                .value_separator
+                .unwrap_or_else(|| convention.value_separator());
+            write!(w, "{}{}", separator, case.apply(value))?;
+        }
 
-impl<N: fmt::Display + Sized + Copy> AttrClass<N> {
+        Ok(())
+    }
+}
+
+impl<N: Clone> AttrClass<N> {
     pub(crate) fn new(parent: N) -> Self {
         Self {
             parent,
             attrs: SmallVec::new(),
+            groups: SmallVec::new(),
+            states: SmallVec::new(),
+            value_separator: None,
+            is_modifiers_only: false,
+            case: None,
+        }
+    }
+
+    pub fn el<E: fmt::Display + Clone>(&self, class: E) -> ElClass<N, E> {
+        ElClass::new(self.parent.clone(), class)
+    }
+
+    pub fn maybe_el<E: fmt::Display + Clone>(&self, class: E, is_set: bool) -> ElClass<N, E> {
+        ElClass::new_maybe(self.parent.clone(), if is_set { Some(class) } else { None })
+    }
+
+    /// Adds a modifier, such as `button--large`.
+    ///
+    /// This accepts anything which can be printed, such as an enum of statuses.
+    /// See `IntoModifier`.
+    pub fn attr<A: IntoModifier>(mut self, attr: A) -> Self {
+        let name = attr.into_modifier();
+        self.remove_grouped(&name);
+        self.attrs.push(Attr { name, value: None });
+        self
+    }
+
+    /// Declares a group of modifiers, where only one can be set at a time.
+    ///
+    /// Setting a member of the group replaces any member set before it.
+    /// Key-value modifiers are not members, as `attr_value` already
+    /// replaces an earlier value for the same key.
+    pub fn group(mut self, group: ModifierGroup) -> Self {
+        // Of the members already set, only the last is kept.
+        if let Some(last) = self.attrs.iter().rposition(|attr| attr.is_in(group)) {
+            let mut i = 0;
+            self.attrs.retain(|attr| {
+                let is_kept = i == last || !attr.is_in(group);
+                i += 1;
+                is_kept
+            });
         }
+
+        self.groups.push(group);
+        self
+    }
+
+    fn remove_grouped(&mut self, name: &str) {
+        for group in &self.groups {
+            if group.contains(name) {
+                self.attrs.retain(|attr| !attr.is_in(*group));
+            }
+        }
+    }
+
+    /// Adds a key-value modifier, such as `button--size_large`.
+    ///
+    /// This replaces any value set before for the same key.
+    pub fn attr_value<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: IntoModifier,
+        V: fmt::Display,
+    {
+        let name = key.into_modifier();
+        self.attrs
+            .retain(|attr| attr.value.is_none() || attr.name != name);
+        self.attrs.push(Attr {
+            name,
+            value: Some(value.to_string().into()),
+        });
+        self
     }
 
-    pub fn el<'a>(&self, class: &'a str) -> ElClass<N, &'a str> {
-        ElClass::new(self.parent, class)
+    /// Prints only the modifier classes, such as `card--large`, without the parent `card`.
+    ///
+    /// This is for when modifier classes already include the styles of
+    /// their parent, such as with Sass `@extend`. If no modifiers are
+    /// set, the parent is still printed.
+    pub fn modifiers_only(mut self) -> Self {
+        self.is_modifiers_only = true;
+        self
     }
 
-    pub fn attr(mut self, attr: &'static str) -> Self {
-        self.attrs.push(attr);
+    /// Prints the modifiers and states in the case given.
+    ///
+    /// The class they are set on keeps its own case.
+    pub fn with_case(mut self, case: Case) -> Self {
+        self.case = Some(case);
         self
     }
 
-    pub fn maybe_attr(self, attr: &'static str, is_set: bool) -> Self {
+    /// Sets the separator placed between the key and value of key-value modifiers.
+    ///
+    /// Defaults to the separator of the block's naming convention.
+    pub fn value_separator(mut self, separator: &'static str) -> Self {
+        self.value_separator = Some(separator);
+        self
+    }
+
+    pub fn maybe_attr<A: IntoModifier>(self, attr: A, is_set: bool) -> Self {
         if is_set {
             self.attr(attr)
         } else {
             self
         }
     }
+
+    /// Sets each modifier which is paired with true, such as `[("active", is_active)]`.
+    ///
+    /// This also accepts a `HashMap` or `BTreeMap` of modifiers.
+    /// Note a `HashMap` will print its modifiers in an arbitrary order.
+    pub fn attrs<I, A>(self, attrs: I) -> Self
+    where
+        I: IntoIterator<Item = (A, bool)>,
+        A: IntoModifier,
+    {
+        attrs
+            .into_iter()
+            .fold(self, |class, (attr, is_set)| class.maybe_attr(attr, is_set))
+    }
+
+    /// Sets a state class, such as `is-active`, which is printed without the block.
+    ///
+    /// The prefix comes from the block's naming convention.
+    pub fn state<A: IntoModifier>(mut self, state: A) -> Self {
+        let state = state.into_modifier();
+
+        if !self.states.contains(&state) {
+            self.states.push(state);
+        }
+
+        self
+    }
+
+    pub fn maybe_state<A: IntoModifier>(self, state: A, is_set: bool) -> Self {
+        if is_set {
+            self.state(state)
+        } else {
+            self
+        }
+    }
+
+    pub fn attr_opt<A: IntoModifier>(self, attr: Option<A>) -> Self {
+        match attr {
+            Some(attr) => self.attr(attr),
+            None => self,
+        }
+    }
 }
 
 impl<N, O> Add<O> for AttrClass<N>
 where
-    N: Class,
+    N: BemClass,
     O: Class,
 {
     type Output = DuoClass<Self, O>;
@@ -56,50 +323,62 @@ where
     }
 }
 
-impl<'s, N> Add<&'s str> for AttrClass<N>
+impl<N, O> Add<Option<O>> for AttrClass<N>
 where
-    N: Class,
+    N: BemClass,
+    O: Class,
 {
-    type Output = DuoClass<Self, &'s str>;
+    type Output = DuoClass<Self, OptionClass<O>>;
 
-    fn add(self, other: &'s str) -> Self::Output {
-        DuoClass::new(self, other)
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
     }
 }
 
-impl<N, O> Add<Option<O>> for AttrClass<N>
+impl<N, O> Add<(O, bool)> for AttrClass<N>
 where
-    N: Class,
+    N: BemClass,
     O: Class,
 {
     type Output = DuoClass<Self, OptionClass<O>>;
 
-    fn add(self, other: Option<O>) -> Self::Output {
-        DuoClass::new(self, OptionClass::new(other))
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
     }
 }
 
-impl<N: fmt::Display> fmt::Display for AttrClass<N> {
+impl<N: BemClass> fmt::Display for AttrClass<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.parent)?;
+        let mut writer = TokenWriter::new(f);
+
+        if self.is_parent_printed() {
+            write!(writer, "{}", self.parent)?;
+        }
 
         for attr in &self.attrs {
-            write!(f, " {}--{}", self.parent, attr)?;
+            write!(writer, " ")?;
+            self.write_attr(&mut writer, attr)?;
+        }
+
+        let state_prefix = self.parent.convention().state_prefix();
+        let case = self.case();
+        for state in &self.states {
+            write!(writer, " {}{}", state_prefix, case.apply(state))?;
         }
 
         Ok(())
     }
 }
 
-impl<'a, N: fmt::Display> From<AttrClass<N>> for Cow<'a, str> {
+impl<'a, N: BemClass> From<AttrClass<N>> for Cow<'a, str> {
     fn from(class: AttrClass<N>) -> Self {
-        class.to_string().into()
+        render(&class).into()
     }
 }
 
-impl<'a, N: fmt::Display> From<AttrClass<N>> for String {
+impl<N: BemClass> From<AttrClass<N>> for String {
     fn from(class: AttrClass<N>) -> Self {
-        class.to_string()
+        render(&class)
     }
 }
 
@@ -136,6 +415,303 @@ mod maybe_attr {
     }
 }
 
+#[cfg(test)]
+mod attr {
+    use super::*;
+    use crate::classes::*;
+
+    #[test]
+    fn it_should_set_owned_attrs() {
+        let status = "pending".to_string();
+        let class = AttrClass::new(BaseClass::new("badge")).attr(status);
+        assert_eq!(class.to_string(), "badge badge--pending");
+    }
+
+    #[test]
+    fn it_should_mix_static_and_owned_attrs() {
+        let class = AttrClass::new(BaseClass::new("badge"))
+            .attr("large")
+            .attr(format!("level-{}", 3))
+            .attr(Cow::Borrowed("bold"));
+        assert_eq!(
+            class.to_string(),
+            "badge badge--large badge--level-3 badge--bold"
+        );
+    }
+
+    enum Status {
+        Pending,
+        Shipped,
+    }
+
+    impl fmt::Display for Status {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Status::Pending => write!(f, "pending"),
+                Status::Shipped => write!(f, "shipped"),
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_accept_display_modifiers() {
+        let class = BaseClass::new("badge")
+            .attr(Status::Pending)
+            .maybe_attr(Status::Shipped, true);
+        assert_eq!(class.to_string(), "badge badge--pending badge--shipped");
+        assert!(class.has_modifier("pending"));
+    }
+
+    #[test]
+    fn it_should_accept_owned_modifiers_on_elements() {
+        let name = format!("size-{}", 2);
+        let class = BaseClass::new("badge").el("icon").attr(name);
+        assert_eq!(class.to_string(), "badge__icon badge__icon--size-2");
+    }
+}
+
+#[cfg(test)]
+mod attr_value {
+    use super::*;
+    use crate::classes::*;
+
+    #[test]
+    fn it_should_set_key_value_attrs() {
+        let class = AttrClass::new(BaseClass::new("button")).attr_value("size", "large");
+        assert_eq!(class.to_string(), "button button--size_large");
+    }
+
+    #[test]
+    fn it_should_accept_display_values() {
+        let class = AttrClass::new(BaseClass::new("grid"))
+            .attr("wide")
+            .attr_value("columns", 3);
+        assert_eq!(class.to_string(), "grid grid--wide grid--columns_3");
+    }
+
+    #[test]
+    fn it_should_use_custom_value_separator() {
+        let class = AttrClass::new(BaseClass::new("menu"))
+            .value_separator("-")
+            .attr_value("theme", "dark");
+        assert_eq!(class.to_string(), "menu menu--theme-dark");
+    }
+
+    #[test]
+    fn it_should_replace_earlier_values_for_the_same_key() {
+        let class = AttrClass::new(BaseClass::new("b"))
+            .attr_value("size", "small")
+            .attr("bold")
+            .attr_value("size", "large");
+        assert_eq!(class.to_string(), "b b--bold b--size_large");
+        assert_eq!(class.modifier_value("size"), Some("large"));
+    }
+}
+
+#[cfg(test)]
+mod tokens {
+    use super::*;
+    use crate::classes::*;
+
+    #[test]
+    fn it_should_return_parent_and_each_attr() {
+        let class = AttrClass::new(BaseClass::new("card"))
+            .attr("large")
+            .attr_value("size", 2);
+        let tokens = class.tokens().collect::<Vec<_>>();
+        assert_eq!(tokens, vec!["card", "card--large", "card--size_2"]);
+    }
+}
+
+#[cfg(test)]
+mod attr_opt {
+    use super::*;
+    use crate::classes::*;
+
+    #[test]
+    fn it_should_only_set_attr_if_some() {
+        let theme: Option<String> = Some("dark".to_string());
+        let class = AttrClass::new(BaseClass::new("menu"))
+            .attr_opt(theme)
+            .attr_opt(None::<&str>);
+        assert_eq!(class.to_string(), "menu menu--dark");
+    }
+}
+
+#[cfg(test)]
+mod attrs {
+    use super::*;
+    use crate::classes::*;
+    use ::std::collections::BTreeMap;
+
+    #[test]
+    fn it_should_set_attrs_paired_with_true() {
+        let class = AttrClass::new(BaseClass::new("input"))
+            .attr("large")
+            .attrs([("active", true), ("disabled", false), ("focused", true)]);
+        assert_eq!(
+            class.to_string(),
+            "input input--large input--active input--focused"
+        );
+    }
+
+    #[test]
+    fn it_should_set_attrs_from_maps() {
+        let mut state = BTreeMap::new();
+        state.insert("disabled".to_string(), true);
+        state.insert("active".to_string(), false);
+        state.insert("error".to_string(), true);
+
+        let class = AttrClass::new(BaseClass::new("input")).attrs(state);
+        assert_eq!(class.to_string(), "input input--disabled input--error");
+    }
+}
+
+#[cfg(test)]
+mod with_case {
+    use super::*;
+    use crate::classes::*;
+
+    #[test]
+    fn it_should_convert_modifiers_values_and_states() {
+        let class = AttrClass::new(BaseClass::new("Button"))
+            .attr("IsPrimary")
+            .attr_value("size", "ExtraLarge")
+            .state("HasFocus")
+            .with_case(Case::Snake);
+        assert_eq!(
+            class.to_string(),
+            "Button Button--is_primary Button--size_extra_large is-has_focus"
+        );
+        assert_eq!(class.rendered_len(), class.to_string().len());
+        assert_eq!(
+            class.tokens().collect::<Vec<_>>(),
+            vec![
+                "Button",
+                "Button--is_primary",
+                "Button--size_extra_large",
+                "is-has_focus"
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod modifiers_only {
+    use super::*;
+    use crate::classes::*;
+
+    #[test]
+    fn it_should_print_only_modifiers() {
+        let class = AttrClass::new(BaseClass::new("card"))
+            .attr("large")
+            .attr_value("size", 2)
+            .state("active")
+            .modifiers_only();
+        assert_eq!(class.to_string(), "card--large card--size_2 is-active");
+        assert_eq!(class.rendered_len(), class.to_string().len());
+        assert_eq!(
+            class.tokens().collect::<Vec<_>>(),
+            vec!["card--large", "card--size_2", "is-active"]
+        );
+    }
+
+    #[test]
+    fn it_should_print_parent_if_no_modifiers_are_set() {
+        let class = AttrClass::new(BaseClass::new("card"))
+            .maybe_attr("large", false)
+            .modifiers_only();
+        assert_eq!(class.to_string(), "card");
+    }
+}
+
+#[cfg(test)]
+mod state {
+    use super::*;
+    use crate::classes::*;
+
+    #[test]
+    fn it_should_print_states_without_the_block() {
+        let class = AttrClass::new(BaseClass::new("tab"))
+            .attr("large")
+            .state("active")
+            .maybe_state("disabled", false)
+            .maybe_state("focused", true);
+        assert_eq!(class.to_string(), "tab tab--large is-active is-focused");
+        assert_eq!(
+            class.tokens().collect::<Vec<_>>(),
+            vec!["tab", "tab--large", "is-active", "is-focused"]
+        );
+        assert!(class.has_state("active"));
+    }
+}
+
+#[cfg(test)]
+mod group {
+    use super::*;
+    use crate::classes::*;
+
+    const SIZE: ModifierGroup = ModifierGroup::new(&["small", "medium", "large"]);
+    const COLOUR: ModifierGroup = ModifierGroup::new(&["red", "blue"]);
+
+    #[test]
+    fn it_should_replace_earlier_members_of_group() {
+        let class = AttrClass::new(BaseClass::new("button"))
+            .group(SIZE)
+            .attr("small")
+            .attr("bold")
+            .attr("large");
+        assert_eq!(class.to_string(), "button button--bold button--large");
+    }
+
+    #[test]
+    fn it_should_keep_last_member_set_before_group_declared() {
+        let class = AttrClass::new(BaseClass::new("button"))
+            .attr("small")
+            .attr("medium")
+            .attr("red")
+            .group(SIZE)
+            .group(COLOUR)
+            .attr("blue");
+        assert_eq!(class.to_string(), "button button--medium button--blue");
+    }
+
+    #[test]
+    fn it_should_allow_callers_to_override_members() {
+        let component_class = AttrClass::new(BaseClass::new("button"))
+            .group(SIZE)
+            .attr("medium");
+        let class = component_class.maybe_attr("small", true);
+        assert_eq!(class.to_string(), "button button--small");
+    }
+}
+
+#[cfg(test)]
+mod structure {
+    use crate::classes::*;
+    use crate::BemClass;
+
+    #[test]
+    fn it_should_return_block_and_modifiers() {
+        let class = BaseClass::new("card")
+            .el("title")
+            .attr("large")
+            .attr_value("size", 2);
+        assert_eq!(class.block(), "card");
+        assert_eq!(class.parent().element(), Some(&"title"));
+        assert_eq!(class.modifiers().collect::<Vec<_>>(), vec!["large", "size"]);
+        assert_eq!(class.modifier_value("size"), Some("2"));
+        assert_eq!(class.modifier_value("large"), None);
+    }
+
+    #[test]
+    fn it_should_check_if_modifier_is_set() {
+        let class = BaseClass::new("card").attr("large");
+        assert!(class.has_modifier("large"));
+        assert!(!class.has_modifier("small"));
+    }
+}
+
 #[cfg(test)]
 mod el {
     use super::*;
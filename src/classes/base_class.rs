@@ -1,49 +1,179 @@
-use crate::classes::{AttrClass, DuoClass, ElClass, OptionClass};
+use crate::classes::{AttrClass, DuoClass, ElClass, MixClass, OptionClass};
+use crate::conventions::TwoDashes;
+use crate::render::render;
+use crate::BemClass;
+use crate::Case;
 use crate::Class;
+use crate::Convention;
+use crate::IntoModifier;
+use crate::ModifierGroup;
+use crate::Namespace;
+use crate::Tokens;
+use crate::{NameStyle, PlainName, StyledName};
 use ::std::borrow::Cow;
 use ::std::convert::From;
 use ::std::fmt;
 use ::std::ops::Add;
 
-#[derive(Copy, Clone, PartialEq, Debug)]
-pub struct BaseClass {
-    class: &'static str,
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BaseClass<C = &'static str, S = TwoDashes, F = PlainName> {
+    class: C,
+    convention: S,
+    style: F,
 }
 
-impl Class for BaseClass {}
+impl<C, S, F> Class for BaseClass<C, S, F>
+where
+    C: AsRef<str> + Clone + PartialEq,
+    S: Convention,
+    F: NameStyle,
+{
+    fn tokens(&self) -> Tokens<'_> {
+        let token = if self.is_printed_unchanged() {
+            Cow::Borrowed(self.name())
+        } else {
+            Cow::Owned(render(self))
+        };
 
-impl BaseClass {
-    pub fn new(base: &'static str) -> Self {
-        Self { class: base }
+        // An empty name is no class at all, rather than an empty one.
+        Tokens::new(::std::iter::once(token).filter(|token| !token.is_empty()))
     }
+}
 
-    pub fn class(&self) -> &'static str {
-        self.class
+impl<C, S, F> BemClass for BaseClass<C, S, F>
+where
+    C: AsRef<str> + Clone + PartialEq,
+    S: Convention,
+    F: NameStyle,
+{
+    type Convention = S;
+
+    /// Returns the name of the block, without its namespace.
+    fn block(&self) -> &str {
+        self.name()
     }
 
-    pub fn el<'a>(self, class: &'a str) -> ElClass<Self, &'a str> {
+    fn convention(&self) -> S {
+        self.convention
+    }
+
+    fn case(&self) -> Case {
+        self.style.case()
+    }
+}
+
+impl<C: AsRef<str> + Clone> BaseClass<C> {
+    pub fn new(base: C) -> Self {
+        Self {
+            class: base,
+            convention: TwoDashes,
+            style: PlainName,
+        }
+    }
+}
+
+impl<C: AsRef<str> + Clone, S: Convention, F: NameStyle> BaseClass<C, S, F> {
+    /// Changes the naming convention used to print this block,
+    /// and the elements and modifiers made from it.
+    pub fn with_convention<T: Convention>(self, convention: T) -> BaseClass<C, T, F> {
+        BaseClass {
+            class: self.class,
+            convention,
+            style: self.style,
+        }
+    }
+
+    /// Prints this block, and the elements and modifiers made from it, in the case given.
+    ///
+    /// i.e. `PrimaryButton` is printed as `primary-button` with `Case::Kebab`.
+    pub fn with_case(self, case: Case) -> BaseClass<C, S, StyledName> {
+        BaseClass {
+            class: self.class,
+            convention: self.convention,
+            style: StyledName::from_style(self.style).with_case(case),
+        }
+    }
+
+    /// Prints this block with the prefix of the namespace, such as `c-card`.
+    pub fn with_namespace(self, namespace: Namespace) -> BaseClass<C, S, StyledName> {
+        BaseClass {
+            class: self.class,
+            convention: self.convention,
+            style: StyledName::from_style(self.style).with_namespace(namespace),
+        }
+    }
+
+    /// Returns the name of this block, without its namespace.
+    pub fn name(&self) -> &str {
+        self.class.as_ref()
+    }
+
+    pub fn namespace(&self) -> Option<Namespace> {
+        self.style.namespace()
+    }
+
+    pub fn el<E: fmt::Display + Clone>(self, class: E) -> ElClass<Self, E> {
         ElClass::new(self, class)
     }
 
-    pub fn attr(self, attr: &'static str) -> AttrClass<Self> {
+    pub fn maybe_el<E: fmt::Display + Clone>(self, class: E, is_set: bool) -> ElClass<Self, E> {
+        ElClass::new_maybe(self, if is_set { Some(class) } else { None })
+    }
+
+    pub fn attr<A: IntoModifier>(self, attr: A) -> AttrClass<Self> {
         AttrClass::new(self).attr(attr)
     }
 
-    pub fn maybe_attr(self, attr: &'static str, is_set: bool) -> AttrClass<Self> {
+    pub fn maybe_attr<A: IntoModifier>(self, attr: A, is_set: bool) -> AttrClass<Self> {
         AttrClass::new(self).maybe_attr(attr, is_set)
     }
-}
 
-impl<'s> Add<&'s str> for BaseClass {
-    type Output = DuoClass<Self, &'s str>;
+    pub fn attr_opt<A: IntoModifier>(self, attr: Option<A>) -> AttrClass<Self> {
+        AttrClass::new(self).attr_opt(attr)
+    }
 
-    fn add(self, other: &'s str) -> Self::Output {
-        DuoClass::new(self, other)
+    pub fn attrs<I, A>(self, attrs: I) -> AttrClass<Self>
+    where
+        I: IntoIterator<Item = (A, bool)>,
+        A: IntoModifier,
+    {
+        AttrClass::new(self).attrs(attrs)
+    }
+
+    pub fn attr_value<K, V>(self, key: K, value: V) -> AttrClass<Self>
+    where
+        K: IntoModifier,
+        V: fmt::Display,
+    {
+        AttrClass::new(self).attr_value(key, value)
+    }
+
+    pub fn group(self, group: ModifierGroup) -> AttrClass<Self> {
+        AttrClass::new(self).group(group)
+    }
+
+    /// Sets a state class, such as `is-active`, which is printed without the block.
+    pub fn state<A: IntoModifier>(self, state: A) -> AttrClass<Self> {
+        AttrClass::new(self).state(state)
+    }
+
+    pub fn maybe_state<A: IntoModifier>(self, state: A, is_set: bool) -> AttrClass<Self> {
+        AttrClass::new(self).maybe_state(state, is_set)
+    }
+
+    /// Mixes another block into this block, such as `card user-card`.
+    pub fn mix<M: Class>(self, block: M) -> MixClass<Self, M>
+    where
+        Self: Class,
+    {
+        MixClass::new(self, block)
     }
 }
 
-impl<O> Add<Option<O>> for BaseClass
+impl<C, S, F, O> Add<Option<O>> for BaseClass<C, S, F>
 where
+    C: AsRef<str>,
+    F: NameStyle,
     O: Class,
 {
     type Output = DuoClass<Self, OptionClass<O>>;
@@ -53,8 +183,23 @@ where
     }
 }
 
-impl<O> Add<O> for BaseClass
+impl<C, S, F, O> Add<(O, bool)> for BaseClass<C, S, F>
 where
+    C: AsRef<str>,
+    F: NameStyle,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
+    }
+}
+
+impl<C, S, F, O> Add<O> for BaseClass<C, S, F>
+where
+    C: AsRef<str>,
+    F: NameStyle,
     O: Class,
 {
     type Output = DuoClass<Self, O>;
@@ -64,27 +209,191 @@ where
     }
 }
 
-impl fmt::Display for BaseClass {
+impl<S, F> BaseClass<&'static str, S, F> {
+    /// Returns the name of this block, without its namespace.
+    pub fn class(&self) -> &'static str {
+        self.class
+    }
+}
+
+impl<C: AsRef<str>, S, F: NameStyle> BaseClass<C, S, F> {
+    /// Returns true if this prints as just its name, with no namespace or case change.
+    fn is_printed_unchanged(&self) -> bool {
+        self.style.namespace().is_none() && self.style.case().is_conforming(self.class.as_ref())
+    }
+}
+
+impl<C: AsRef<str>, S, F: NameStyle> fmt::Display for BaseClass<C, S, F> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.class)
+        if let Some(namespace) = self.style.namespace() {
+            write!(f, "{}", namespace.prefix())?;
+        }
+
+        write!(f, "{}", self.style.case().apply(self.class.as_ref()))
+    }
+}
+
+impl<'a, C, S, F> From<BaseClass<C, S, F>> for Cow<'a, str>
+where
+    C: Into<Cow<'a, str>> + AsRef<str>,
+    F: NameStyle,
+{
+    fn from(class: BaseClass<C, S, F>) -> Self {
+        if class.is_printed_unchanged() {
+            class.class.into()
+        } else {
+            Cow::Owned(render(&class))
+        }
+    }
+}
+
+/// Only blocks with a `PlainName` can be borrowed, as they print their name unchanged.
+impl<'a, S> From<BaseClass<&'a str, S, PlainName>> for &'a str {
+    fn from(class: BaseClass<&'a str, S, PlainName>) -> Self {
+        class.class
+    }
+}
+
+impl<C, S, F> From<BaseClass<C, S, F>> for String
+where
+    C: Into<String> + AsRef<str>,
+    F: NameStyle,
+{
+    fn from(class: BaseClass<C, S, F>) -> Self {
+        if class.is_printed_unchanged() {
+            class.class.into()
+        } else {
+            render(&class)
+        }
+    }
+}
+
+#[cfg(test)]
+mod attrs {
+    use super::*;
+    use ::std::collections::HashMap;
+
+    #[test]
+    fn is_should_set_attrs_from_hash_map() {
+        let mut state = HashMap::new();
+        state.insert("active", true);
+        state.insert("disabled", false);
+
+        let class = BaseClass::new("mr-component").attrs(state);
+        assert_eq!("mr-component mr-component--active", class.to_string())
+    }
+}
+
+#[cfg(test)]
+mod with_case {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_block_elements_and_modifiers() {
+        let class = BaseClass::new("PrimaryButton")
+            .with_case(Case::Kebab)
+            .el("IconLeft")
+            .attr("is_active");
+        assert_eq!(
+            class.to_string(),
+            "primary-button__icon-left primary-button__icon-left--is-active"
+        );
+    }
+
+    #[test]
+    fn it_should_convert_when_turned_into_strings() {
+        let class = BaseClass::new("PrimaryButton").with_case(Case::Snake);
+        assert_eq!(class.tokens().collect::<Vec<_>>(), vec!["primary_button"]);
+        assert_eq!(String::from(class), "primary_button");
+    }
+
+    #[test]
+    fn it_should_borrow_conforming_names() {
+        let class = BaseClass::new("primary-button").with_case(Case::Kebab);
+        let tokens = class.tokens().collect::<Vec<_>>();
+        assert!(matches!(tokens[0], Cow::Borrowed("primary-button")));
+        assert!(matches!(Cow::from(class), Cow::Borrowed("primary-button")));
+    }
+}
+
+#[cfg(test)]
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_should_accept_borrowed_names() {
+        let name = "mr-component".to_string();
+        let class = BaseClass::new(name.as_str()).el("child").attr("red");
+        assert_eq!(
+            "mr-component__child mr-component__child--red",
+            class.to_string()
+        )
+    }
+
+    #[test]
+    fn it_should_accept_owned_names() {
+        let class = BaseClass::new("mr-component".to_string()).attr("red");
+        assert_eq!("mr-component mr-component--red", class.to_string())
+    }
+
+    #[test]
+    fn it_should_accept_cow_names() {
+        let name: Cow<'static, str> = Cow::Owned("mr-component".to_string());
+        let class = BaseClass::new(name).el("child");
+        assert_eq!("mr-component__child", class.to_string())
     }
 }
 
-impl<'a> From<BaseClass> for Cow<'a, str> {
-    fn from(class: BaseClass) -> Self {
-        class.class().into()
+#[cfg(test)]
+mod class {
+    use super::*;
+    use crate::classname;
+
+    #[test]
+    fn it_should_return_static_names_beyond_the_class() {
+        let name: &'static str = {
+            let class = classname("mr-component").with_case(Case::Kebab);
+            class.class()
+        };
+        assert_eq!(name, "mr-component");
+    }
+
+    #[test]
+    fn it_should_return_runtime_names() {
+        let class = BaseClass::new("mr-component".to_string());
+        assert_eq!(class.name(), "mr-component");
     }
 }
 
-impl<'a> From<BaseClass> for &'a str {
-    fn from(class: BaseClass) -> Self {
-        class.class()
+#[cfg(test)]
+mod tokens {
+    use super::*;
+    use crate::classes::EmptyClass;
+    use crate::same_classes;
+
+    #[test]
+    fn it_should_not_return_empty_names() {
+        assert_eq!(BaseClass::new("").tokens().count(), 0);
+        assert!(same_classes(&BaseClass::new(""), &EmptyClass));
     }
 }
 
-impl From<BaseClass> for String {
-    fn from(class: BaseClass) -> Self {
-        class.class().to_string()
+#[cfg(test)]
+mod from {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_plain_names() {
+        let name = "card".to_string();
+        let class: &str = BaseClass::new(name.as_str()).into();
+        assert_eq!(class, "card");
+    }
+
+    #[test]
+    fn it_should_print_styled_names() {
+        let class = BaseClass::new("card").with_namespace(Namespace::COMPONENT);
+        assert_eq!(String::from(class), "c-card");
+        assert_eq!(Cow::from(class.with_case(Case::Snake)), "c-card");
     }
 }
 
@@ -0,0 +1,406 @@
+use crate::classes::{DuoClass, OptionClass};
+use crate::render::render;
+use crate::Class;
+use crate::Tokens;
+use ::std::borrow::Cow;
+use ::std::convert::From;
+use ::std::fmt;
+use ::std::iter::{FromIterator, Sum};
+use ::std::ops;
+
+///
+/// A mutable set of class names.
+///
+/// Unlike the other classes, a `ClassList` keeps the same type as
+/// classes are added and removed. So it can be built up in a loop.
+/// Each class name is held once, in the order it was first added.
+/// Note that if `std::ops::Add` is imported, `list.add(class)` will
+/// resolve to the `+` operator. Use `ClassList::add(&mut list, class)`.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct ClassList {
+    classes: Vec<Cow<'static, str>>,
+}
+
+impl Class for ClassList {
+    fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(self.iter().map(Cow::Borrowed))
+    }
+}
+
+impl ClassList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new list, holding each of the class names of the class given.
+    ///
+    /// Collections of classes can be converted using `From` or `collect` instead.
+    pub fn from_class<C: Class>(class: C) -> Self {
+        let mut list = Self::new();
+        list.add(class);
+        list
+    }
+
+    /// Adds each of the class names of the class given, skipping those already held.
+    pub fn add<C: Class>(&mut self, class: C) {
+        for token in class.tokens() {
+            if !token.is_empty() && !self.contains(&token) {
+                self.classes.push(Cow::Owned(token.into_owned()));
+            }
+        }
+    }
+
+    /// Removes each of the class names given, split on whitespace.
+    /// Returns true if any were held.
+    pub fn remove(&mut self, names: &str) -> bool {
+        let len = self.classes.len();
+        for name in names.split_whitespace() {
+            self.classes.retain(|class| class != name);
+        }
+        self.classes.len() != len
+    }
+
+    /// Adds the class names if `is_set` is true, and removes them if false.
+    ///
+    /// Like `add`, the names given are split on whitespace.
+    pub fn toggle<S: Into<Cow<'static, str>>>(&mut self, names: S, is_set: bool) {
+        let names = names.into();
+
+        if !is_set {
+            self.remove(&names);
+            return;
+        }
+
+        match names {
+            Cow::Borrowed(names) => {
+                for name in names.split_whitespace() {
+                    self.insert(Cow::Borrowed(name));
+                }
+            }
+            Cow::Owned(names) if !names.is_empty() && !names.contains(char::is_whitespace) => {
+                self.insert(Cow::Owned(names));
+            }
+            Cow::Owned(names) => {
+                for name in names.split_whitespace() {
+                    self.insert(Cow::Owned(name.to_string()));
+                }
+            }
+        }
+    }
+
+    /// Adds a single class name, if it is not already held.
+    fn insert(&mut self, name: Cow<'static, str>) {
+        if !self.contains(&name) {
+            self.classes.push(name);
+        }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.classes.iter().any(|class| class == name)
+    }
+
+    /// Returns the class names held, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.classes.iter().map(|class| class.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.classes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+}
+
+impl<C: Class> Extend<C> for ClassList {
+    fn extend<I: IntoIterator<Item = C>>(&mut self, classes: I) {
+        for class in classes {
+            self.add(class);
+        }
+    }
+}
+
+impl<C: Class> Extend<Option<C>> for ClassList {
+    fn extend<I: IntoIterator<Item = Option<C>>>(&mut self, classes: I) {
+        self.extend(classes.into_iter().flatten());
+    }
+}
+
+impl<C: Class> FromIterator<C> for ClassList {
+    fn from_iter<I: IntoIterator<Item = C>>(classes: I) -> Self {
+        let mut list = Self::new();
+        list.extend(classes);
+        list
+    }
+}
+
+impl<C: Class> FromIterator<Option<C>> for ClassList {
+    fn from_iter<I: IntoIterator<Item = Option<C>>>(classes: I) -> Self {
+        classes.into_iter().flatten().collect()
+    }
+}
+
+/// Summing into a `ClassList` drops duplicate class names.
+/// To keep them, as with `+`, sum into a `VecClass` instead.
+impl<C: Class> Sum<C> for ClassList {
+    fn sum<I: Iterator<Item = C>>(classes: I) -> Self {
+        classes.collect()
+    }
+}
+
+impl<C: Class> From<Vec<C>> for ClassList {
+    fn from(classes: Vec<C>) -> Self {
+        classes.into_iter().collect()
+    }
+}
+
+impl<C: Class> From<Vec<Option<C>>> for ClassList {
+    fn from(classes: Vec<Option<C>>) -> Self {
+        classes.into_iter().collect()
+    }
+}
+
+impl<'a, C: Class> From<&'a [C]> for ClassList {
+    fn from(classes: &'a [C]) -> Self {
+        classes.iter().cloned().collect()
+    }
+}
+
+impl<'a, C: Class> From<&'a [Option<C>]> for ClassList {
+    fn from(classes: &'a [Option<C>]) -> Self {
+        classes.iter().cloned().collect()
+    }
+}
+
+impl<C: Class, const N: usize> From<[C; N]> for ClassList {
+    fn from(classes: [C; N]) -> Self {
+        IntoIterator::into_iter(classes).collect()
+    }
+}
+
+impl<C: Class, const N: usize> From<[Option<C>; N]> for ClassList {
+    fn from(classes: [Option<C>; N]) -> Self {
+        IntoIterator::into_iter(classes).collect()
+    }
+}
+
+macro_rules! impl_from_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: Class),+> From<($($name,)+)> for ClassList {
+            #[allow(non_snake_case)]
+            fn from(($($name,)+): ($($name,)+)) -> Self {
+                let mut list = Self::new();
+                $(list.add($name);)+
+                list
+            }
+        }
+    };
+}
+
+impl_from_tuple!(A);
+impl_from_tuple!(A, B);
+impl_from_tuple!(A, B, C);
+impl_from_tuple!(A, B, C, D);
+impl_from_tuple!(A, B, C, D, E);
+impl_from_tuple!(A, B, C, D, E, F);
+impl_from_tuple!(A, B, C, D, E, F, G);
+impl_from_tuple!(A, B, C, D, E, F, G, H);
+
+impl<O> ops::Add<O> for ClassList
+where
+    O: Class,
+{
+    type Output = DuoClass<Self, O>;
+
+    fn add(self, other: O) -> Self::Output {
+        DuoClass::new(self, other)
+    }
+}
+
+impl<O> ops::Add<Option<O>> for ClassList
+where
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
+    }
+}
+
+impl<O> ops::Add<(O, bool)> for ClassList
+where
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
+    }
+}
+
+impl fmt::Display for ClassList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, class) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{}", class)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> From<ClassList> for Cow<'a, str> {
+    fn from(class: ClassList) -> Self {
+        render(&class).into()
+    }
+}
+
+impl From<ClassList> for String {
+    fn from(class: ClassList) -> Self {
+        render(&class)
+    }
+}
+
+#[cfg(test)]
+mod add {
+    use super::*;
+    use crate::classname;
+
+    #[test]
+    fn it_should_add_each_class_name_once() {
+        let mut list = ClassList::new();
+        list.add(classname("card").attr("large"));
+        list.add("card grid");
+        assert_eq!(list.to_string(), "card card--large grid");
+    }
+
+    #[test]
+    fn it_should_be_built_in_a_loop() {
+        let mut list = ClassList::from_class(classname("list"));
+        for i in 0..3 {
+            list.add(classname("list").attr_value("level", i));
+        }
+        assert_eq!(
+            list.to_string(),
+            "list list--level_0 list--level_1 list--level_2"
+        );
+    }
+
+    #[test]
+    fn it_should_skip_empty_classes() {
+        let list = ClassList::from(vec![classname("a"), classname(""), classname("b")]);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.to_string(), "a b");
+    }
+}
+
+#[cfg(test)]
+mod remove {
+    use super::*;
+
+    #[test]
+    fn it_should_remove_class_names() {
+        let mut list = ClassList::from_class("card grid");
+        assert!(list.remove("card"));
+        assert!(!list.remove("card"));
+        assert_eq!(list.to_string(), "grid");
+    }
+
+    #[test]
+    fn it_should_remove_each_class_name_split_on_whitespace() {
+        let mut list = ClassList::from_class("card grid active");
+        assert!(list.remove(" card  active "));
+        assert_eq!(list.to_string(), "grid");
+    }
+}
+
+#[cfg(test)]
+mod toggle {
+    use super::*;
+
+    #[test]
+    fn it_should_add_or_remove_class_names() {
+        let mut list = ClassList::from_class("card");
+        list.toggle("active", true);
+        list.toggle("active", true);
+        assert!(list.contains("active"));
+        assert_eq!(list.to_string(), "card active");
+
+        list.toggle("active", false);
+        assert!(!list.contains("active"));
+        assert_eq!(list.to_string(), "card");
+    }
+
+    #[test]
+    fn it_should_toggle_each_class_name_split_on_whitespace() {
+        let mut list = ClassList::from_class("card");
+        list.toggle("a  b", true);
+        list.toggle(" b c ".to_string(), true);
+        list.toggle(String::new(), true);
+        assert!(list.contains("a"));
+        assert_eq!(list.iter().collect::<Vec<_>>(), ["card", "a", "b", "c"]);
+
+        list.toggle("c a".to_string(), false);
+        assert_eq!(list.to_string(), "card b");
+    }
+}
+
+#[cfg(test)]
+mod from {
+    use super::*;
+    use crate::classname;
+
+    #[test]
+    fn it_should_collect_classes() {
+        let list: ClassList = ["a", "b", "a"]
+            .iter()
+            .map(|&name| classname(name))
+            .collect();
+        assert_eq!(list.to_string(), "a b");
+    }
+
+    #[test]
+    fn it_should_sum_classes() {
+        let list: ClassList = (1..=2)
+            .map(|i| classname("col").attr_value("span", i))
+            .sum();
+        assert_eq!(list.to_string(), "col col--span_1 col--span_2");
+    }
+
+    #[test]
+    fn it_should_convert_collections_of_classes() {
+        let list = ClassList::from(vec![classname("a"), classname("b")]);
+        assert_eq!(list.to_string(), "a b");
+
+        let list = ClassList::from([Some(classname("a")), None, Some(classname("c"))]);
+        assert_eq!(list.to_string(), "a c");
+
+        let list = ClassList::from(&["a", "b"][..]);
+        assert_eq!(list.to_string(), "a b");
+    }
+
+    #[test]
+    fn it_should_convert_tuples_of_classes() {
+        let list = ClassList::from((classname("card").el("title"), "bold", classname("grid")));
+        assert_eq!(list.to_string(), "card__title bold grid");
+    }
+}
+
+#[cfg(test)]
+mod compose {
+    use super::*;
+    use crate::classname;
+
+    #[test]
+    fn it_should_compose_with_other_classes() {
+        let list = ClassList::from_class("active");
+        let class = classname("card").el("title") + list;
+        assert_eq!(class.to_string(), "card__title active");
+    }
+}
@@ -0,0 +1,132 @@
+use crate::classes::{DuoClass, OptionClass};
+use crate::render::render;
+use crate::Class;
+use crate::Tokens;
+use ::std::borrow::Cow;
+use ::std::convert::From;
+use ::std::fmt;
+use ::std::ops::Add;
+
+///
+/// Prints each class token of the wrapped class only once.
+///
+/// Tokens are printed in the order they first occur.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct DedupClass<C> {
+    class: C,
+}
+
+impl<C> Class for DedupClass<C>
+where
+    C: Class,
+{
+    fn tokens(&self) -> Tokens<'_> {
+        let mut seen = Vec::new();
+        let tokens = self.class.tokens().filter(move |token| {
+            if token.is_empty() || seen.contains(token) {
+                false
+            } else {
+                seen.push(token.clone());
+                true
+            }
+        });
+
+        Tokens::new(tokens)
+    }
+}
+
+impl<C> DedupClass<C>
+where
+    C: Class,
+{
+    pub(crate) fn new(class: C) -> Self {
+        Self { class }
+    }
+}
+
+impl<C, O> Add<O> for DedupClass<C>
+where
+    C: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, O>;
+
+    fn add(self, other: O) -> Self::Output {
+        DuoClass::new(self, other)
+    }
+}
+
+impl<C, O> Add<Option<O>> for DedupClass<C>
+where
+    C: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
+    }
+}
+
+impl<C, O> Add<(O, bool)> for DedupClass<C>
+where
+    C: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
+    }
+}
+
+impl<C: Class> fmt::Display for DedupClass<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, token) in self.tokens().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{}", token)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, C: Class> From<DedupClass<C>> for Cow<'a, str> {
+    fn from(class: DedupClass<C>) -> Self {
+        render(&class).into()
+    }
+}
+
+impl<C: Class> From<DedupClass<C>> for String {
+    fn from(class: DedupClass<C>) -> Self {
+        render(&class)
+    }
+}
+
+#[cfg(test)]
+mod display {
+    use crate::*;
+
+    #[test]
+    fn it_should_print_duplicate_classes_once() {
+        let class = (classname("card") + "card" + classname("card").attr("large")).dedup();
+        assert_eq!(class.to_string(), "card card--large");
+    }
+
+    #[test]
+    fn it_should_keep_first_occurrence_order() {
+        let class =
+            (classname("card").attr("large") + "grid" + classname("card").attr("large")).dedup();
+        assert_eq!(class.to_string(), "card card--large grid");
+    }
+
+    #[test]
+    fn it_should_skip_empty_classes() {
+        let class = (classname("a") + classname("") + "b").dedup();
+        assert_eq!(class.to_string(), "a b");
+    }
+}
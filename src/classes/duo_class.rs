@@ -1,11 +1,15 @@
 use crate::classes::OptionClass;
+use crate::render::render;
+use crate::token_writer::TokenWriter;
 use crate::Class;
+use crate::Tokens;
 use ::std::borrow::Cow;
 use ::std::convert::From;
 use ::std::fmt;
+use ::std::fmt::Write;
 use ::std::ops::Add;
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct DuoClass<L, R> {
     left: L,
     right: R,
@@ -20,9 +24,12 @@ where
 
 impl<L, R> Class for DuoClass<L, R>
 where
-    L: fmt::Display + Sized + PartialEq + Clone,
-    R: fmt::Display + Sized + PartialEq + Clone,
+    L: Class,
+    R: Class,
 {
+    fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(self.left.tokens().chain(self.right.tokens()))
+    }
 }
 
 impl<L, R> DuoClass<L, R>
@@ -33,17 +40,10 @@ where
     pub(crate) fn new(left: L, right: R) -> Self {
         Self { left, right }
     }
-}
-
-impl<'s, L, R> Add<&'s str> for DuoClass<L, R>
-where
-    L: fmt::Display + Sized,
-    R: fmt::Display + Sized,
-{
-    type Output = DuoClass<Self, &'s str>;
 
-    fn add(self, other: &'s str) -> Self::Output {
-        DuoClass::new(self, other)
+    /// Returns the two classes added together.
+    pub fn parts(&self) -> (&L, &R) {
+        (&self.left, &self.right)
     }
 }
 
@@ -73,20 +73,77 @@ where
     }
 }
 
+impl<L, R, O> Add<(O, bool)> for DuoClass<L, R>
+where
+    L: fmt::Display + Sized,
+    R: fmt::Display + Sized,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
+    }
+}
+
 impl<L: fmt::Display, R: fmt::Display> fmt::Display for DuoClass<L, R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.left, self.right)
+        let mut writer = TokenWriter::new(f);
+        write!(writer, "{} {}", self.left, self.right)
     }
 }
 
 impl<'a, L: fmt::Display, R: fmt::Display> From<DuoClass<L, R>> for Cow<'a, str> {
     fn from(class: DuoClass<L, R>) -> Self {
-        class.to_string().into()
+        render(&class).into()
     }
 }
 
-impl<'a, L: fmt::Display, R: fmt::Display> From<DuoClass<L, R>> for String {
+impl<L: fmt::Display, R: fmt::Display> From<DuoClass<L, R>> for String {
     fn from(class: DuoClass<L, R>) -> Self {
-        class.to_string()
+        render(&class)
+    }
+}
+
+#[cfg(test)]
+mod tokens {
+    use crate::*;
+
+    #[test]
+    fn it_should_return_tokens_of_both_sides() {
+        let class = classname("card").el("title") + "bold  wide" + None::<classes::BaseClass>;
+        let tokens = class.tokens().collect::<Vec<_>>();
+        assert_eq!(tokens, vec!["card__title", "bold", "wide"]);
+    }
+}
+
+#[cfg(test)]
+mod parts {
+    use crate::*;
+
+    #[test]
+    fn it_should_return_both_classes() {
+        let class = classname("card") + classname("grid").attr("wide");
+        let (left, right) = class.parts();
+        assert_eq!(left.block(), "card");
+        assert!(right.has_modifier("wide"));
+    }
+}
+
+#[cfg(test)]
+mod display {
+    use super::*;
+    use crate::classes::BaseClass;
+
+    #[test]
+    fn it_should_skip_empty_parts() {
+        let class = DuoClass::new(DuoClass::new("", BaseClass::new("page")), " ");
+        assert_eq!(class.to_string(), "page");
+    }
+
+    #[test]
+    fn it_should_normalise_whitespace_between_parts() {
+        let class = DuoClass::new(BaseClass::new("page"), "  home_page   noscript ");
+        assert_eq!(class.to_string(), "page home_page noscript");
     }
 }
@@ -0,0 +1,122 @@
+use crate::classes::{DuoClass, OptionClass};
+use crate::render::render;
+use crate::Class;
+use crate::Tokens;
+use ::std::borrow::Cow;
+use ::std::convert::From;
+use ::std::fmt;
+use ::std::ops::Add;
+
+///
+/// Holds one of two different classes.
+///
+/// This allows branches, such as an `if` and `else`, to return
+/// different classes without boxing them. For more than two
+/// different classes, these can be nested.
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum EitherClass<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Class for EitherClass<L, R>
+where
+    L: Class,
+    R: Class,
+{
+    fn tokens(&self) -> Tokens<'_> {
+        match self {
+            Self::Left(class) => class.tokens(),
+            Self::Right(class) => class.tokens(),
+        }
+    }
+}
+
+impl<L, R, O> Add<O> for EitherClass<L, R>
+where
+    L: Class,
+    R: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, O>;
+
+    fn add(self, other: O) -> Self::Output {
+        DuoClass::new(self, other)
+    }
+}
+
+impl<L, R, O> Add<Option<O>> for EitherClass<L, R>
+where
+    L: Class,
+    R: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
+    }
+}
+
+impl<L, R, O> Add<(O, bool)> for EitherClass<L, R>
+where
+    L: Class,
+    R: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
+    }
+}
+
+impl<L: fmt::Display, R: fmt::Display> fmt::Display for EitherClass<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left(class) => write!(f, "{}", class),
+            Self::Right(class) => write!(f, "{}", class),
+        }
+    }
+}
+
+impl<'a, L: fmt::Display, R: fmt::Display> From<EitherClass<L, R>> for Cow<'a, str> {
+    fn from(class: EitherClass<L, R>) -> Self {
+        render(&class).into()
+    }
+}
+
+impl<L: fmt::Display, R: fmt::Display> From<EitherClass<L, R>> for String {
+    fn from(class: EitherClass<L, R>) -> Self {
+        render(&class)
+    }
+}
+
+#[cfg(test)]
+mod display {
+    use super::*;
+    use crate::classname;
+
+    fn title_class(is_link: bool) -> EitherClass<impl Class, impl Class> {
+        let base_class = classname("card");
+
+        if is_link {
+            EitherClass::Left(base_class.attr("link"))
+        } else {
+            EitherClass::Right(base_class.el("title"))
+        }
+    }
+
+    #[test]
+    fn it_should_print_the_class_held() {
+        assert_eq!(title_class(true).to_string(), "card card--link");
+        assert_eq!(title_class(false).to_string(), "card__title");
+    }
+
+    #[test]
+    fn it_should_compose_with_other_classes() {
+        let class = title_class(false) + "bold";
+        assert_eq!(String::from(class), "card__title bold");
+    }
+}
@@ -1,42 +1,157 @@
-use crate::classes::{AttrClass, DuoClass, OptionClass};
+use crate::classes::{AttrClass, DuoClass, MixClass, OptionClass};
+use crate::render::render;
+use crate::BemClass;
+use crate::Case;
 use crate::Class;
+use crate::Convention;
+use crate::IntoModifier;
+use crate::ModifierGroup;
+use crate::Tokens;
 use ::std::borrow::Cow;
 use ::std::convert::From;
 use ::std::fmt;
 use ::std::ops::Add;
 
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct ElClass<N, C: fmt::Display + Clone = &'static str> {
     parent: N,
-    class: C,
+    class: Option<C>,
+    case: Option<Case>,
 }
 
-impl<N> Class for ElClass<N> where N: fmt::Display + Sized + PartialEq + Clone {}
+impl<N, C> Class for ElClass<N, C>
+where
+    N: BemClass,
+    C: fmt::Display + Clone + PartialEq,
+{
+    fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(::std::iter::once(Cow::Owned(render(self))))
+    }
+}
+
+impl<N, C> BemClass for ElClass<N, C>
+where
+    N: BemClass,
+    C: fmt::Display + Clone + PartialEq,
+{
+    type Convention = N::Convention;
+
+    fn block(&self) -> &str {
+        self.parent.block()
+    }
+
+    fn convention(&self) -> Self::Convention {
+        self.parent.convention()
+    }
+
+    fn case(&self) -> Case {
+        self.element_case()
+    }
+}
+
+impl<N: BemClass, C: fmt::Display + Clone> ElClass<N, C> {
+    fn element_case(&self) -> Case {
+        self.case.unwrap_or_else(|| self.parent.case())
+    }
+}
+
+impl<N: Clone, C: fmt::Display + Clone> ElClass<N, C> {
+    /// Returns the class this is an element of.
+    pub fn parent(&self) -> &N {
+        &self.parent
+    }
 
-impl<N: Sized + fmt::Display + Copy> ElClass<N> {
-    pub(crate) fn new<'a>(parent: N, class: &'a str) -> ElClass<N, &'a str> {
-        ElClass { parent, class }
+    /// Returns the name of this element.
+    ///
+    /// This is `None` when made by `maybe_el` with `is_set` as false.
+    pub fn element(&self) -> Option<&C> {
+        self.class.as_ref()
     }
 
-    pub fn el(self, class: &'static str) -> ElClass<Self> {
-        ElClass {
-            parent: self,
+    pub(crate) fn new(parent: N, class: C) -> Self {
+        Self::new_maybe(parent, Some(class))
+    }
+
+    /// When no class is given, the element prints as just its parent.
+    pub(crate) fn new_maybe(parent: N, class: Option<C>) -> Self {
+        Self {
+            parent,
             class,
+            case: None,
         }
     }
 
-    pub fn attr(self, attr: &'static str) -> AttrClass<Self> {
+    /// Prints this element, and the classes made from it, in the case given.
+    ///
+    /// The block and parent elements keep their own case. Conventions
+    /// which set the case of elements, such as `Suit`, apply theirs after.
+    pub fn with_case(mut self, case: Case) -> Self {
+        self.case = Some(case);
+        self
+    }
+
+    pub fn el<E: fmt::Display + Clone>(self, class: E) -> ElClass<Self, E> {
+        ElClass::new(self, class)
+    }
+
+    pub fn maybe_el<E: fmt::Display + Clone>(self, class: E, is_set: bool) -> ElClass<Self, E> {
+        ElClass::new_maybe(self, if is_set { Some(class) } else { None })
+    }
+
+    pub fn attr<A: IntoModifier>(self, attr: A) -> AttrClass<Self> {
         AttrClass::new(self).attr(attr)
     }
 
-    pub fn maybe_attr(self, attr: &'static str, is_set: bool) -> AttrClass<Self> {
+    pub fn maybe_attr<A: IntoModifier>(self, attr: A, is_set: bool) -> AttrClass<Self> {
         AttrClass::new(self).maybe_attr(attr, is_set)
     }
+
+    pub fn attr_opt<A: IntoModifier>(self, attr: Option<A>) -> AttrClass<Self> {
+        AttrClass::new(self).attr_opt(attr)
+    }
+
+    pub fn attrs<I, A>(self, attrs: I) -> AttrClass<Self>
+    where
+        I: IntoIterator<Item = (A, bool)>,
+        A: IntoModifier,
+    {
+        AttrClass::new(self).attrs(attrs)
+    }
+
+    pub fn attr_value<K, V>(self, key: K, value: V) -> AttrClass<Self>
+    where
+        K: IntoModifier,
+        V: fmt::Display,
+    {
+        AttrClass::new(self).attr_value(key, value)
+    }
+
+    pub fn group(self, group: ModifierGroup) -> AttrClass<Self> {
+        AttrClass::new(self).group(group)
+    }
+
+    /// Sets a state class, such as `is-active`, which is printed without the block.
+    pub fn state<A: IntoModifier>(self, state: A) -> AttrClass<Self> {
+        AttrClass::new(self).state(state)
+    }
+
+    pub fn maybe_state<A: IntoModifier>(self, state: A, is_set: bool) -> AttrClass<Self> {
+        AttrClass::new(self).maybe_state(state, is_set)
+    }
+
+    /// Mixes another block into this class, such as `header__search search-form`.
+    pub fn mix<M: Class>(self, block: M) -> MixClass<Self, M>
+    where
+        Self: Class,
+    {
+        MixClass::new(self, block)
+    }
 }
 
-impl<N, O> Add<O> for ElClass<N>
+impl<N, C, O> Add<O> for ElClass<N, C>
 where
-    N: Class,
+    N: BemClass,
+    C: fmt::Display + Clone,
     O: Class,
 {
     type Output = DuoClass<Self, O>;
@@ -46,44 +161,63 @@ where
     }
 }
 
-impl<'s, N> Add<&'s str> for ElClass<N>
+impl<N, C, O> Add<Option<O>> for ElClass<N, C>
 where
-    N: Class,
+    N: BemClass,
+    C: fmt::Display + Clone,
+    O: Class,
 {
-    type Output = DuoClass<Self, &'s str>;
+    type Output = DuoClass<Self, OptionClass<O>>;
 
-    fn add(self, other: &'s str) -> Self::Output {
-        DuoClass::new(self, other)
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
     }
 }
 
-impl<N, O> Add<Option<O>> for ElClass<N>
+impl<N, C, O> Add<(O, bool)> for ElClass<N, C>
 where
-    N: Class,
+    N: BemClass,
+    C: fmt::Display + Clone,
     O: Class,
 {
     type Output = DuoClass<Self, OptionClass<O>>;
 
-    fn add(self, other: Option<O>) -> Self::Output {
-        DuoClass::new(self, OptionClass::new(other))
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
     }
 }
 
-impl<N: fmt::Display> fmt::Display for ElClass<N> {
+impl<N: BemClass, C: fmt::Display + Clone> fmt::Display for ElClass<N, C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}__{}", self.parent, self.class)
+        write!(f, "{}", self.parent)?;
+
+        if let Some(class) = &self.class {
+            let convention = self.parent.convention();
+            write!(f, "{}", convention.element_separator())?;
+            convention.write_element(f, &self.element_case().apply(class))?;
+        }
+
+        Ok(())
     }
 }
 
-impl<'a, N: fmt::Display> From<ElClass<N>> for Cow<'a, str> {
-    fn from(class: ElClass<N>) -> Self {
-        class.to_string().into()
+impl<'a, N, C> From<ElClass<N, C>> for Cow<'a, str>
+where
+    N: BemClass,
+    C: fmt::Display + Clone,
+{
+    fn from(class: ElClass<N, C>) -> Self {
+        render(&class).into()
     }
 }
 
-impl<'a, N: fmt::Display> From<ElClass<N>> for String {
-    fn from(class: ElClass<N>) -> Self {
-        class.to_string()
+impl<N, C> From<ElClass<N, C>> for String
+where
+    N: BemClass,
+    C: fmt::Display + Clone,
+{
+    fn from(class: ElClass<N, C>) -> Self {
+        render(&class)
     }
 }
 
@@ -118,3 +252,123 @@ mod maybe_attr {
         )
     }
 }
+
+#[cfg(test)]
+mod el {
+    use crate::*;
+
+    #[test]
+    fn it_should_print_runtime_element_names() {
+        let name = "child".to_string();
+        let el = classname("mr-component").el(&name);
+        assert_eq!("mr-component__child", el.to_string())
+    }
+
+    #[test]
+    fn it_should_print_nested_runtime_element_names() {
+        let el = classname("mr-component")
+            .el("child".to_string())
+            .el(format!("item-{}", 2))
+            .attr("red");
+        assert_eq!(
+            "mr-component__child__item-2 mr-component__child__item-2--red",
+            el.to_string()
+        )
+    }
+
+    #[test]
+    fn it_should_compose_runtime_element_names() {
+        let name = "child".to_string();
+        let class = classname("mr-component").el(name.as_str()) + classname("other");
+        assert_eq!("mr-component__child other", class.to_string());
+        assert_eq!("mr-component__child other", String::from(class));
+    }
+}
+
+#[cfg(test)]
+mod with_case {
+    use crate::*;
+
+    #[test]
+    fn it_should_convert_only_this_element_and_its_children() {
+        let el = classname("Card")
+            .el("HeaderRow")
+            .with_case(Case::Kebab)
+            .el("MainTitle")
+            .attr("IsLarge");
+        assert_eq!(
+            el.to_string(),
+            "Card__header-row__main-title Card__header-row__main-title--is-large"
+        );
+    }
+
+    #[test]
+    fn it_should_convert_before_the_convention() {
+        let el = classname("Card")
+            .with_convention(conventions::Suit)
+            .el("main_title")
+            .with_case(Case::Kebab);
+        assert_eq!(el.to_string(), "Card-mainTitle");
+    }
+}
+
+#[cfg(test)]
+mod structure {
+    use crate::*;
+
+    #[test]
+    fn it_should_return_block_and_element() {
+        let el = classname("card").el("header").el("title");
+        assert_eq!(el.block(), "card");
+        assert_eq!(el.element(), Some(&"title"));
+        assert_eq!(el.parent().element(), Some(&"header"));
+    }
+}
+
+#[cfg(test)]
+mod maybe_el {
+    use crate::*;
+
+    #[test]
+    fn it_should_set_el_if_is_set() {
+        let el = classname("card").el("header").maybe_el("icon", true);
+        assert_eq!("card__header__icon", el.to_string())
+    }
+
+    #[test]
+    fn it_should_print_parent_if_is_set_is_false() {
+        let el = classname("card").maybe_el("icon", false).attr("large");
+        assert_eq!("card card--large", el.to_string())
+    }
+}
+
+#[cfg(test)]
+mod attr_opt {
+    use crate::*;
+
+    #[test]
+    fn it_should_set_attr_if_some() {
+        let el = classname("card").el("title").attr_opt(Some("large"));
+        assert_eq!("card__title card__title--large", el.to_string())
+    }
+
+    #[test]
+    fn it_should_not_set_attr_if_none() {
+        let el = classname("card").el("title").attr_opt(None::<&str>);
+        assert_eq!("card__title", el.to_string())
+    }
+}
+
+#[cfg(test)]
+mod attr_value {
+    use crate::*;
+
+    #[test]
+    fn it_should_set_key_value_attr_on_child() {
+        let el = classname("card").el("title");
+        assert_eq!(
+            "card__title card__title--size_2",
+            el.attr_value("size", 2).to_string()
+        )
+    }
+}
@@ -0,0 +1,93 @@
+use crate::classes::{DuoClass, OptionClass};
+use crate::Class;
+use crate::Tokens;
+use ::std::borrow::Cow;
+use ::std::convert::From;
+use ::std::fmt;
+use ::std::iter::Sum;
+use ::std::ops::Add;
+
+///
+/// A class which prints nothing.
+///
+/// Adding it to another class makes no difference to what is printed.
+/// This makes it a starting point when folding classes together.
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct EmptyClass;
+
+impl Class for EmptyClass {
+    fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(::std::iter::empty())
+    }
+}
+
+impl<O> Add<O> for EmptyClass
+where
+    O: Class,
+{
+    type Output = DuoClass<Self, O>;
+
+    fn add(self, other: O) -> Self::Output {
+        DuoClass::new(self, other)
+    }
+}
+
+impl<O> Add<Option<O>> for EmptyClass
+where
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
+    }
+}
+
+impl<O> Add<(O, bool)> for EmptyClass
+where
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
+    }
+}
+
+/// The sum of empty classes is empty.
+impl Sum for EmptyClass {
+    fn sum<I: Iterator<Item = EmptyClass>>(_: I) -> Self {
+        EmptyClass
+    }
+}
+
+impl fmt::Display for EmptyClass {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl<'a> From<EmptyClass> for Cow<'a, str> {
+    fn from(_: EmptyClass) -> Self {
+        Cow::Borrowed("")
+    }
+}
+
+impl From<EmptyClass> for String {
+    fn from(_: EmptyClass) -> Self {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod add {
+    use super::*;
+    use crate::classname;
+
+    #[test]
+    fn it_should_make_no_difference_when_added() {
+        let class = EmptyClass + classname("card") + EmptyClass;
+        assert_eq!(class.to_string(), "card");
+    }
+}
@@ -0,0 +1,143 @@
+use crate::classes::{DuoClass, OptionClass};
+use crate::render::render;
+use crate::token_writer::TokenWriter;
+use crate::Class;
+use crate::Tokens;
+use ::std::borrow::Cow;
+use ::std::convert::From;
+use ::std::fmt;
+use ::std::fmt::Write;
+use ::std::ops::Add;
+
+///
+/// A BEM mix, of a class from one block with a class from another.
+///
+/// i.e. `header__search search-form`, where the `search` element of
+/// the `header` block is mixed with the `search-form` block.
+///
+/// This prints the same as adding the two classes together. However
+/// it records which class is the host, and which is mixed into it.
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MixClass<H, M> {
+    host: H,
+    mixed: M,
+}
+
+impl<H, M> Class for MixClass<H, M>
+where
+    H: Class,
+    M: Class,
+{
+    fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(self.host.tokens().chain(self.mixed.tokens()))
+    }
+}
+
+impl<H, M> MixClass<H, M>
+where
+    H: Class,
+    M: Class,
+{
+    pub(crate) fn new(host: H, mixed: M) -> Self {
+        Self { host, mixed }
+    }
+
+    /// The class which the other block is mixed into.
+    pub fn host(&self) -> &H {
+        &self.host
+    }
+
+    /// The class of the block mixed into the host.
+    pub fn mixed(&self) -> &M {
+        &self.mixed
+    }
+
+    /// Returns the host and mixed classes.
+    pub fn parts(&self) -> (&H, &M) {
+        (&self.host, &self.mixed)
+    }
+}
+
+impl<H, M, O> Add<O> for MixClass<H, M>
+where
+    H: Class,
+    M: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, O>;
+
+    fn add(self, other: O) -> Self::Output {
+        DuoClass::new(self, other)
+    }
+}
+
+impl<H, M, O> Add<Option<O>> for MixClass<H, M>
+where
+    H: Class,
+    M: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
+    }
+}
+
+impl<H, M, O> Add<(O, bool)> for MixClass<H, M>
+where
+    H: Class,
+    M: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
+    }
+}
+
+impl<H: fmt::Display, M: fmt::Display> fmt::Display for MixClass<H, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut writer = TokenWriter::new(f);
+        write!(writer, "{} {}", self.host, self.mixed)
+    }
+}
+
+impl<'a, H: fmt::Display, M: fmt::Display> From<MixClass<H, M>> for Cow<'a, str> {
+    fn from(class: MixClass<H, M>) -> Self {
+        render(&class).into()
+    }
+}
+
+impl<H: fmt::Display, M: fmt::Display> From<MixClass<H, M>> for String {
+    fn from(class: MixClass<H, M>) -> Self {
+        render(&class)
+    }
+}
+
+#[cfg(test)]
+mod mix {
+    use crate::*;
+
+    #[test]
+    fn it_should_print_element_mixed_with_block() {
+        let class = classname("header")
+            .el("search")
+            .mix(classname("search-form").attr("wide"));
+        assert_eq!(
+            class.to_string(),
+            "header__search search-form search-form--wide"
+        );
+    }
+
+    #[test]
+    fn it_should_keep_host_and_mixed_blocks() {
+        let class = classname("header")
+            .el("search")
+            .mix(classname("search-form"));
+        assert_eq!(class.host().to_string(), "header__search");
+        assert_eq!(class.mixed().to_string(), "search-form");
+    }
+}
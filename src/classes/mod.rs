@@ -1,11 +1,27 @@
+mod any_bem_class;
+mod any_class;
 mod attr_class;
 mod base_class;
+mod class_list;
+mod dedup_class;
 mod duo_class;
+mod either_class;
 mod el_class;
+mod empty_class;
+mod mix_class;
 mod option_class;
+mod vec_class;
 
+pub use self::any_bem_class::AnyBemClass;
+pub use self::any_class::AnyClass;
 pub use self::attr_class::AttrClass;
 pub use self::base_class::BaseClass;
+pub use self::class_list::ClassList;
+pub use self::dedup_class::DedupClass;
 pub use self::duo_class::DuoClass;
+pub use self::either_class::EitherClass;
 pub use self::el_class::ElClass;
+pub use self::empty_class::EmptyClass;
+pub use self::mix_class::MixClass;
 pub use self::option_class::OptionClass;
+pub use self::vec_class::VecClass;
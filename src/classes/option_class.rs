@@ -1,16 +1,30 @@
 use crate::classes::DuoClass;
+use crate::render::render;
+use crate::token_writer::TokenWriter;
 use crate::Class;
+use crate::Tokens;
 use ::std::borrow::Cow;
 use ::std::convert::From;
 use ::std::fmt;
+use ::std::fmt::Write;
 use ::std::ops::Add;
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct OptionClass<C> {
     option: Option<C>,
 }
 
-impl<C> Class for OptionClass<C> where C: fmt::Display + Sized + PartialEq + Clone {}
+impl<C> Class for OptionClass<C>
+where
+    C: Class,
+{
+    fn tokens(&self) -> Tokens<'_> {
+        match &self.option {
+            Some(class) => class.tokens(),
+            None => Tokens::new(::std::iter::empty()),
+        }
+    }
+}
 
 impl<C> OptionClass<C>
 where
@@ -19,6 +33,10 @@ where
     pub(crate) fn new(option: Option<C>) -> Self {
         Self { option }
     }
+
+    pub fn as_option(&self) -> Option<&C> {
+        self.option.as_ref()
+    }
 }
 
 impl<C, O> Add<O> for OptionClass<C>
@@ -33,33 +51,34 @@ where
     }
 }
 
-impl<'s, C> Add<&'s str> for OptionClass<C>
+impl<C, O> Add<Option<O>> for OptionClass<C>
 where
     C: Class,
+    O: Class,
 {
-    type Output = DuoClass<Self, &'s str>;
+    type Output = DuoClass<Self, OptionClass<O>>;
 
-    fn add(self, other: &'s str) -> Self::Output {
-        DuoClass::new(self, other)
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
     }
 }
 
-impl<C, O> Add<Option<O>> for OptionClass<C>
+impl<C, O> Add<(O, bool)> for OptionClass<C>
 where
     C: Class,
     O: Class,
 {
     type Output = DuoClass<Self, OptionClass<O>>;
 
-    fn add(self, other: Option<O>) -> Self::Output {
-        DuoClass::new(self, OptionClass::new(other))
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
     }
 }
 
 impl<C: fmt::Display> fmt::Display for OptionClass<C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(class) = &self.option {
-            write!(f, "{}", class)?;
+            write!(TokenWriter::new(f), "{}", class)?;
         }
 
         Ok(())
@@ -68,13 +87,13 @@ impl<C: fmt::Display> fmt::Display for OptionClass<C> {
 
 impl<'a, C: fmt::Display> From<OptionClass<C>> for Cow<'a, str> {
     fn from(class: OptionClass<C>) -> Self {
-        class.to_string().into()
+        render(&class).into()
     }
 }
 
-impl<'a, C: fmt::Display> From<OptionClass<C>> for String {
+impl<C: fmt::Display> From<OptionClass<C>> for String {
     fn from(class: OptionClass<C>) -> Self {
-        class.to_string()
+        render(&class)
     }
 }
 
@@ -94,4 +113,10 @@ mod display {
         let o = OptionClass::<BaseClass>::new(Some(BaseClass::new("home-page")));
         assert_eq!(o.to_string(), "home-page");
     }
+
+    #[test]
+    fn it_should_trim_whitespace_of_class_provided() {
+        let o = OptionClass::new(Some(" home-page "));
+        assert_eq!(o.to_string(), "home-page");
+    }
 }
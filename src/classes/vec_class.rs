@@ -0,0 +1,156 @@
+use crate::classes::{DuoClass, EmptyClass, OptionClass};
+use crate::render::render;
+use crate::token_writer::TokenWriter;
+use crate::Class;
+use crate::Tokens;
+use ::std::borrow::Cow;
+use ::std::convert::From;
+use ::std::fmt;
+use ::std::fmt::Write;
+use ::std::iter::{FromIterator, Sum};
+use ::std::ops::Add;
+
+///
+/// A sequence of classes of the same type, printed one after another.
+///
+/// Unlike `ClassList`, duplicate class names are all printed.
+/// So summing classes into a `VecClass` prints the same as adding
+/// them with `+`. Summing no classes prints nothing, like `EmptyClass`.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct VecClass<C> {
+    classes: Vec<C>,
+}
+
+impl<C> Class for VecClass<C>
+where
+    C: Class,
+{
+    fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(self.classes.iter().flat_map(|class| class.tokens()))
+    }
+}
+
+impl<C, O> Add<O> for VecClass<C>
+where
+    C: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, O>;
+
+    fn add(self, other: O) -> Self::Output {
+        DuoClass::new(self, other)
+    }
+}
+
+impl<C, O> Add<Option<O>> for VecClass<C>
+where
+    C: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, other: Option<O>) -> Self::Output {
+        DuoClass::new(self, OptionClass::new(other))
+    }
+}
+
+impl<C, O> Add<(O, bool)> for VecClass<C>
+where
+    C: Class,
+    O: Class,
+{
+    type Output = DuoClass<Self, OptionClass<O>>;
+
+    fn add(self, (other, is_set): (O, bool)) -> Self::Output {
+        DuoClass::new(self, other.when(is_set))
+    }
+}
+
+impl<C: fmt::Display> fmt::Display for VecClass<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut writer = TokenWriter::new(f);
+
+        for class in &self.classes {
+            write!(writer, " {}", class)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> From<Vec<C>> for VecClass<C> {
+    fn from(classes: Vec<C>) -> Self {
+        Self { classes }
+    }
+}
+
+impl<C> From<EmptyClass> for VecClass<C> {
+    fn from(_: EmptyClass) -> Self {
+        Self {
+            classes: Vec::new(),
+        }
+    }
+}
+
+impl<C: Class> FromIterator<C> for VecClass<C> {
+    fn from_iter<I: IntoIterator<Item = C>>(classes: I) -> Self {
+        Self {
+            classes: classes.into_iter().collect(),
+        }
+    }
+}
+
+impl<C: Class> Sum<C> for VecClass<C> {
+    fn sum<I: Iterator<Item = C>>(classes: I) -> Self {
+        classes.collect()
+    }
+}
+
+impl<'a, C: fmt::Display> From<VecClass<C>> for Cow<'a, str> {
+    fn from(class: VecClass<C>) -> Self {
+        render(&class).into()
+    }
+}
+
+impl<C: fmt::Display> From<VecClass<C>> for String {
+    fn from(class: VecClass<C>) -> Self {
+        render(&class)
+    }
+}
+
+#[cfg(test)]
+mod display {
+    use super::*;
+    use crate::classname;
+
+    #[test]
+    fn it_should_print_each_class() {
+        let class = VecClass::from(vec![classname("a"), classname(""), classname("a")]);
+        assert_eq!(class.to_string(), "a a");
+    }
+}
+
+#[cfg(test)]
+mod sum {
+    use super::*;
+    use crate::classes::BaseClass;
+    use crate::{classname, same_classes};
+
+    #[test]
+    fn it_should_print_the_same_as_adding() {
+        let classes = [classname("a"), classname("b"), classname("a")];
+        let sum = classes.iter().copied().sum::<VecClass<_>>();
+        assert_eq!(
+            sum.to_string(),
+            (classes[0] + classes[1] + classes[2]).to_string()
+        );
+    }
+
+    #[test]
+    fn it_should_sum_nothing_to_empty() {
+        let sum = ::std::iter::empty::<BaseClass>().sum::<VecClass<_>>();
+        assert!(same_classes(&sum, &EmptyClass));
+        assert_eq!(sum, VecClass::from(EmptyClass));
+    }
+}
@@ -0,0 +1,238 @@
+//!
+//! Naming conventions, which control the separators used when printing classes.
+//!
+//! The default is `TwoDashes`. A different convention can be chosen
+//! for a block, using `BaseClass::with_convention`. Its elements and
+//! modifiers are then printed using the same convention.
+//!
+//! ```
+//! use ::classnames::classname;
+//! use ::classnames::conventions::ReactStyle;
+//!
+//! // Prints "Card-title Card-title_size_large"
+//! let class = classname("Card")
+//!     .with_convention(ReactStyle)
+//!     .el("title")
+//!     .attr_value("size", "large");
+//! ```
+//!
+
+use crate::case::CaseWriter;
+use crate::Case;
+use ::std::fmt;
+use ::std::fmt::Write;
+use ::std::hash::Hash;
+
+pub trait Convention: Copy + PartialEq + Eq + Hash + fmt::Debug {
+    /// The separator between a block and its element. i.e. `__` in `card__title`.
+    fn element_separator(&self) -> &'static str;
+
+    /// The separator before a modifier. i.e. `--` in `card--large`.
+    fn modifier_separator(&self) -> &'static str;
+
+    /// The separator between the key and value of a modifier. i.e. `_` in `card--size_large`.
+    fn value_separator(&self) -> &'static str;
+
+    /// The prefix of state classes. i.e. `is-` in `is-active`.
+    fn state_prefix(&self) -> &'static str {
+        "is-"
+    }
+
+    /// When true, modifier classes are printed without their parent.
+    /// i.e. `card--large` instead of `card card--large`.
+    ///
+    /// See `AttrClass::modifiers_only`.
+    fn is_modifiers_only(&self) -> bool {
+        false
+    }
+
+    /// Writes the name of an element. By default this is written unchanged.
+    fn write_element(
+        &self,
+        writer: &mut dyn fmt::Write,
+        element: &dyn fmt::Display,
+    ) -> fmt::Result {
+        write!(writer, "{}", element)
+    }
+}
+
+/// The two dashes style of BEM. i.e. `block__elem--mod_value`. This is the default.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct TwoDashes;
+
+impl Convention for TwoDashes {
+    fn element_separator(&self) -> &'static str {
+        "__"
+    }
+
+    fn modifier_separator(&self) -> &'static str {
+        "--"
+    }
+
+    fn value_separator(&self) -> &'static str {
+        "_"
+    }
+}
+
+/// The classic style of BEM, from Yandex. i.e. `block__elem_mod_value`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Classic;
+
+impl Convention for Classic {
+    fn element_separator(&self) -> &'static str {
+        "__"
+    }
+
+    fn modifier_separator(&self) -> &'static str {
+        "_"
+    }
+
+    fn value_separator(&self) -> &'static str {
+        "_"
+    }
+}
+
+/// The React style of BEM. i.e. `Block-elem_mod_value`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct ReactStyle;
+
+impl Convention for ReactStyle {
+    fn element_separator(&self) -> &'static str {
+        "-"
+    }
+
+    fn modifier_separator(&self) -> &'static str {
+        "_"
+    }
+
+    fn value_separator(&self) -> &'static str {
+        "_"
+    }
+}
+
+/// SUIT CSS naming. i.e. `ComponentName-descendentName--modifierName`.
+///
+/// Descendent names are written in camelCase, so `descendent-name`
+/// is printed as `descendentName`. This is applied after any case
+/// set with `with_case`, so descendent names are always camelCase.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Suit;
+
+impl Convention for Suit {
+    fn element_separator(&self) -> &'static str {
+        "-"
+    }
+
+    fn modifier_separator(&self) -> &'static str {
+        "--"
+    }
+
+    fn value_separator(&self) -> &'static str {
+        "-"
+    }
+
+    fn write_element(
+        &self,
+        writer: &mut dyn fmt::Write,
+        element: &dyn fmt::Display,
+    ) -> fmt::Result {
+        let mut writer = CaseWriter::new(writer, Case::Camel);
+        write!(writer, "{}", element)?;
+        writer.finish()
+    }
+}
+
+#[cfg(test)]
+mod with_convention {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn it_should_print_two_dashes_by_default() {
+        let class = classname("card").el("title").attr_value("size", "large");
+        assert_eq!(class.to_string(), "card__title card__title--size_large");
+    }
+
+    #[test]
+    fn it_should_print_classic_style() {
+        let class = classname("card")
+            .with_convention(Classic)
+            .el("title")
+            .attr("large")
+            .attr_value("size", 2);
+        assert_eq!(
+            class.to_string(),
+            "card__title card__title_large card__title_size_2"
+        );
+    }
+
+    #[test]
+    fn it_should_print_react_style() {
+        let class = classname("Card")
+            .with_convention(ReactStyle)
+            .el("header")
+            .el("title")
+            .attr("large");
+        assert_eq!(
+            class.to_string(),
+            "Card-header-title Card-header-title_large"
+        );
+    }
+
+    #[test]
+    fn it_should_print_suit_style() {
+        let class = classname("MyComponent")
+            .with_convention(Suit)
+            .el("descendent-name")
+            .attr("large")
+            .state("active");
+        assert_eq!(
+            class.to_string(),
+            "MyComponent-descendentName MyComponent-descendentName--large is-active"
+        );
+    }
+
+    #[test]
+    fn it_should_print_modifiers_only_for_conventions_which_ask_for_it() {
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+        struct Extended;
+
+        impl Convention for Extended {
+            fn element_separator(&self) -> &'static str {
+                "__"
+            }
+
+            fn modifier_separator(&self) -> &'static str {
+                "--"
+            }
+
+            fn value_separator(&self) -> &'static str {
+                "_"
+            }
+
+            fn is_modifiers_only(&self) -> bool {
+                true
+            }
+        }
+
+        let class = classname("card").with_convention(Extended);
+        assert_eq!(
+            class.el("title").attr("large").to_string(),
+            "card__title--large"
+        );
+        assert_eq!(
+            class.attr("large").attr("red").to_string(),
+            "card--large card--red"
+        );
+    }
+
+    #[test]
+    fn it_should_keep_convention_when_creating_children_from_attrs() {
+        let class = classname("Card").with_convention(ReactStyle).attr("large");
+        assert_eq!(class.el("title").to_string(), "Card-title");
+        assert_eq!(
+            class.tokens().collect::<Vec<_>>(),
+            vec!["Card", "Card_large"]
+        );
+    }
+}
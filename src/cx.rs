@@ -0,0 +1,143 @@
+use crate::classes::{OptionClass, VecClass};
+use crate::Class;
+
+///
+/// Converts a value into a class, for use with the `cx!` macro.
+///
+/// This is implemented for all classes, and for `Option`s,
+/// `(class, bool)` pairs, arrays, and `Vec`s of them.
+///
+pub trait IntoClass {
+    type Class: Class;
+
+    fn into_class(self) -> Self::Class;
+}
+
+impl<C: Class> IntoClass for C {
+    type Class = C;
+
+    fn into_class(self) -> Self::Class {
+        self
+    }
+}
+
+impl<T: IntoClass> IntoClass for Option<T> {
+    type Class = OptionClass<T::Class>;
+
+    fn into_class(self) -> Self::Class {
+        OptionClass::new(self.map(IntoClass::into_class))
+    }
+}
+
+impl<T: IntoClass> IntoClass for (T, bool) {
+    type Class = OptionClass<T::Class>;
+
+    fn into_class(self) -> Self::Class {
+        let (class, is_set) = self;
+        class.into_class().when(is_set)
+    }
+}
+
+impl<T: IntoClass> IntoClass for Vec<T> {
+    type Class = VecClass<T::Class>;
+
+    fn into_class(self) -> Self::Class {
+        self.into_iter()
+            .map(IntoClass::into_class)
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+impl<T: IntoClass, const N: usize> IntoClass for [T; N] {
+    type Class = VecClass<T::Class>;
+
+    fn into_class(self) -> Self::Class {
+        IntoIterator::into_iter(self)
+            .map(IntoClass::into_class)
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+///
+/// Joins classes together, following the rules of the npm `classnames` package.
+///
+/// Each argument can be a class, a `&str` or `String`, an `Option`,
+/// a `(class, bool)` pair, or an array or `Vec` of these. `None`,
+/// pairs with `false`, and empty strings are skipped. Arrays are
+/// flattened. The classes left are joined with a single space.
+///
+/// ```
+/// use ::classnames::{classname, cx};
+///
+/// let is_active = true;
+/// let extra: Option<&str> = None;
+///
+/// // Prints "btn btn--large active icon"
+/// let class = cx!(
+///     classname("btn").attr("large"),
+///     ("active", is_active),
+///     ("disabled", false),
+///     extra,
+///     ["", "icon"],
+/// );
+/// assert_eq!(class.to_string(), "btn btn--large active icon");
+/// ```
+///
+#[macro_export]
+macro_rules! cx {
+    () => {
+        $crate::classes::EmptyClass
+    };
+    ($($class:expr),+ $(,)?) => {
+        $crate::classes::EmptyClass $(+ $crate::IntoClass::into_class($class))+
+    };
+}
+
+#[cfg(test)]
+mod cx_macro {
+    use crate::*;
+
+    #[test]
+    fn it_should_print_nothing_for_no_classes() {
+        assert_eq!(cx!().to_string(), "");
+    }
+
+    #[test]
+    fn it_should_skip_falsy_classes() {
+        let none: Option<classes::BaseClass> = None;
+        let class = cx!(
+            "a",
+            "",
+            none,
+            ("b", false),
+            Some("c"),
+            (classname("d"), true)
+        );
+        assert_eq!(class.to_string(), "a c d");
+    }
+
+    #[test]
+    fn it_should_flatten_arrays() {
+        let class = cx!(
+            "a",
+            [Some("b"), None],
+            vec![("c", true), ("d", false)],
+            [["e"]]
+        );
+        assert_eq!(class.to_string(), "a b c e");
+    }
+
+    #[test]
+    fn it_should_accept_owned_strings_and_nested_cx() {
+        let name = format!("level-{}", 2);
+        let class = cx!(name, cx!("a", "b"), classname("card").el("title"));
+        assert_eq!(class.to_string(), "level-2 a b card__title");
+    }
+
+    #[test]
+    fn it_should_not_remove_duplicates() {
+        assert_eq!(cx!("a", "a").to_string(), "a a");
+    }
+}
@@ -0,0 +1,39 @@
+use crate::Class;
+use crate::Tokens;
+use ::std::any::Any;
+use ::std::fmt;
+
+///
+/// An object safe version of `Class`.
+///
+/// This is implemented for every `Class`, and allows classes to be
+/// used as trait objects. i.e. `Box<dyn DynClass>`. See `AnyClass`
+/// for a boxed class, which also implements `Class`.
+///
+pub trait DynClass: fmt::Display {
+    fn dyn_tokens(&self) -> Tokens<'_>;
+
+    fn clone_box(&self) -> Box<dyn DynClass>;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn dyn_eq(&self, other: &dyn DynClass) -> bool;
+}
+
+impl<C: Class + 'static> DynClass for C {
+    fn dyn_tokens(&self) -> Tokens<'_> {
+        self.tokens()
+    }
+
+    fn clone_box(&self) -> Box<dyn DynClass> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn DynClass) -> bool {
+        other.as_any().downcast_ref::<C>() == Some(self)
+    }
+}
@@ -0,0 +1,60 @@
+use ::std::any::Any;
+use ::std::borrow::Cow;
+use ::std::fmt;
+
+///
+/// Converts a value into the name of a modifier or state.
+///
+/// This is implemented for anything which can be printed, such as an
+/// enum of statuses. A `&'static str` is borrowed, and a `String` or
+/// `Cow` is moved, so neither is copied. Other values are formatted.
+///
+pub trait IntoModifier {
+    fn into_modifier(self) -> Cow<'static, str>;
+}
+
+impl<T: fmt::Display + 'static> IntoModifier for T {
+    fn into_modifier(self) -> Cow<'static, str> {
+        // Held in an `Option`, so a matching value can be moved out.
+        let mut value = Some(self);
+        let any = &mut value as &mut dyn Any;
+
+        if let Some(Some(name)) = any.downcast_ref::<Option<&'static str>>() {
+            return Cow::Borrowed(name);
+        }
+
+        if let Some(name) = any.downcast_mut::<Option<String>>() {
+            return Cow::Owned(name.take().unwrap_or_default());
+        }
+
+        if let Some(name) = any.downcast_mut::<Option<Cow<'static, str>>>() {
+            return name.take().unwrap_or_default();
+        }
+
+        Cow::Owned(value.map(|value| value.to_string()).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod conversions {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_static_names() {
+        assert!(matches!("large".into_modifier(), Cow::Borrowed("large")));
+    }
+
+    #[test]
+    fn it_should_move_owned_names() {
+        let name = "large".to_string();
+        let ptr = name.as_ptr();
+        assert_eq!("large".to_string().into_modifier(), "large");
+        assert_eq!(name.into_modifier().as_ptr(), ptr);
+    }
+
+    #[test]
+    fn it_should_format_other_values() {
+        assert_eq!(2.into_modifier(), "2");
+        assert_eq!('x'.into_modifier(), "x");
+    }
+}
@@ -113,11 +113,36 @@
 //  }
 //
 
+mod bem_class;
+mod bem_name;
+mod case;
 mod class;
 pub mod classes;
+pub mod conventions;
+mod cx;
+mod dyn_class;
+mod into_modifier;
+mod modifier_group;
+mod name_style;
+mod namespace;
+mod render;
+mod token_writer;
+mod tokens;
 
+pub use crate::bem_class::BemClass;
+pub use crate::bem_name::{BemModifier, BemName, ParseBemError};
+pub use crate::case::Case;
 pub use crate::class::classname;
+pub use crate::class::same_classes;
 pub use crate::class::Class;
+pub use crate::conventions::Convention;
+pub use crate::cx::IntoClass;
+pub use crate::dyn_class::DynClass;
+pub use crate::into_modifier::IntoModifier;
+pub use crate::modifier_group::ModifierGroup;
+pub use crate::name_style::{NameStyle, PlainName, StyledName};
+pub use crate::namespace::Namespace;
+pub use crate::tokens::Tokens;
 
 #[cfg(test)]
 mod integration {
@@ -194,7 +219,40 @@ mod integration {
     fn it_should_not_print_added_optional_classes_when_none() {
         let base: Option<classes::BaseClass> = None;
         let class = classname("page") + "home_page" + base + "noscript";
-        assert_eq!(class.to_string(), "page home_page  noscript");
+        assert_eq!(class.to_string(), "page home_page noscript");
+    }
+
+    #[test]
+    fn it_should_not_print_spaces_around_optional_classes_when_none() {
+        let base: Option<classes::BaseClass> = None;
+        let class = classname("page") + base + base;
+        assert_eq!(class.to_string(), "page");
+    }
+
+    #[test]
+    fn it_should_print_classes_added_with_true() {
+        let class = classname("page") + (classname("mobile"), true) + ("noscript", false);
+        assert_eq!(class.to_string(), "page mobile");
+    }
+
+    #[test]
+    fn it_should_only_print_classes_when_set() {
+        let class = classname("page") + classname("mobile").when(false) + "noscript".when(true);
+        assert_eq!(class.to_string(), "page noscript");
+    }
+
+    #[test]
+    fn it_should_use_classes_as_hash_keys() {
+        let mut cache = ::std::collections::HashMap::new();
+        cache.insert(classname("page").attr("wide") + "mobile", 1);
+        assert_eq!(
+            cache.get(&(classname("page").attr("wide") + "mobile")),
+            Some(&1)
+        );
+        assert_eq!(
+            cache.get(&(classname("page").attr("wide") + "tablet")),
+            None
+        );
     }
 
     #[test]
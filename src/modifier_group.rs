@@ -0,0 +1,32 @@
+///
+/// A group of modifiers, where only one can be set at a time.
+///
+/// For example a group of sizes ...
+///
+/// ```
+/// use ::classnames::{classname, ModifierGroup};
+///
+/// const SIZE: ModifierGroup = ModifierGroup::new(&["small", "medium", "large"]);
+///
+/// // Prints "button button--large"
+/// let button = classname("button").group(SIZE).attr("small").attr("large");
+/// ```
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierGroup {
+    members: &'static [&'static str],
+}
+
+impl ModifierGroup {
+    pub const fn new(members: &'static [&'static str]) -> Self {
+        Self { members }
+    }
+
+    pub fn members(&self) -> &'static [&'static str] {
+        self.members
+    }
+
+    pub fn contains(&self, modifier: &str) -> bool {
+        self.members.contains(&modifier)
+    }
+}
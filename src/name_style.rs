@@ -0,0 +1,68 @@
+use crate::Case;
+use crate::Namespace;
+use ::std::fmt;
+use ::std::hash::Hash;
+
+///
+/// How the name of a block is printed, such as with a namespace or in a case.
+///
+/// This is part of the type of a `BaseClass`. A block made with
+/// `classname` is a `PlainName`, and prints its name unchanged. Setting
+/// a namespace or case changes it to a `StyledName`.
+///
+pub trait NameStyle: Copy + PartialEq + Eq + Hash + fmt::Debug {
+    fn namespace(&self) -> Option<Namespace>;
+
+    fn case(&self) -> Case;
+}
+
+/// Blocks which print their name as given. This is the default.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct PlainName;
+
+impl NameStyle for PlainName {
+    fn namespace(&self) -> Option<Namespace> {
+        None
+    }
+
+    fn case(&self) -> Case {
+        Case::Preserve
+    }
+}
+
+/// Blocks which print their name with a namespace, or in a case.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct StyledName {
+    namespace: Option<Namespace>,
+    case: Case,
+}
+
+impl StyledName {
+    pub(crate) fn from_style<F: NameStyle>(style: F) -> Self {
+        Self {
+            namespace: style.namespace(),
+            case: style.case(),
+        }
+    }
+
+    pub(crate) fn with_namespace(self, namespace: Namespace) -> Self {
+        Self {
+            namespace: Some(namespace),
+            ..self
+        }
+    }
+
+    pub(crate) fn with_case(self, case: Case) -> Self {
+        Self { case, ..self }
+    }
+}
+
+impl NameStyle for StyledName {
+    fn namespace(&self) -> Option<Namespace> {
+        self.namespace
+    }
+
+    fn case(&self) -> Case {
+        self.case
+    }
+}
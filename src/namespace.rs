@@ -0,0 +1,106 @@
+use crate::classes::BaseClass;
+use crate::conventions::TwoDashes;
+use crate::StyledName;
+
+///
+/// A prefix for the layer a block belongs to, such as `c-` for components.
+///
+/// This follows the ITCSS style of namespaces. The prefix is printed
+/// once before the block, and is carried through to its elements and modifiers.
+///
+/// ```
+/// use ::classnames::Namespace;
+///
+/// // Prints "c-card__title c-card__title--large"
+/// let title = Namespace::component("card").el("title").attr("large");
+/// ```
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Namespace {
+    prefix: &'static str,
+}
+
+impl Namespace {
+    pub const COMPONENT: Namespace = Namespace::new("c-");
+    pub const OBJECT: Namespace = Namespace::new("o-");
+    pub const UTILITY: Namespace = Namespace::new("u-");
+    pub const JS_HOOK: Namespace = Namespace::new("js-");
+
+    pub const fn new(prefix: &'static str) -> Self {
+        Self { prefix }
+    }
+
+    pub fn prefix(&self) -> &'static str {
+        self.prefix
+    }
+
+    /// Creates a block within this namespace.
+    pub fn classname<C: AsRef<str> + Clone>(self, name: C) -> BaseClass<C, TwoDashes, StyledName> {
+        BaseClass::new(name).with_namespace(self)
+    }
+
+    /// Creates a component block, such as `c-card`.
+    pub fn component<C: AsRef<str> + Clone>(name: C) -> BaseClass<C, TwoDashes, StyledName> {
+        Self::COMPONENT.classname(name)
+    }
+
+    /// Creates an object block, such as `o-media`.
+    pub fn object<C: AsRef<str> + Clone>(name: C) -> BaseClass<C, TwoDashes, StyledName> {
+        Self::OBJECT.classname(name)
+    }
+
+    /// Creates a utility class, such as `u-hidden`.
+    pub fn utility<C: AsRef<str> + Clone>(name: C) -> BaseClass<C, TwoDashes, StyledName> {
+        Self::UTILITY.classname(name)
+    }
+
+    /// Creates a class for JavaScript to find, such as `js-modal-open`.
+    pub fn js_hook<C: AsRef<str> + Clone>(name: C) -> BaseClass<C, TwoDashes, StyledName> {
+        Self::JS_HOOK.classname(name)
+    }
+}
+
+#[cfg(test)]
+mod classname {
+    use super::*;
+    use crate::Class;
+
+    #[test]
+    fn it_should_prefix_each_layer() {
+        assert_eq!(Namespace::component("card").to_string(), "c-card");
+        assert_eq!(Namespace::object("media").to_string(), "o-media");
+        assert_eq!(Namespace::utility("hidden").to_string(), "u-hidden");
+        assert_eq!(
+            Namespace::js_hook("modal-open").to_string(),
+            "js-modal-open"
+        );
+    }
+
+    #[test]
+    fn it_should_carry_prefix_through_elements_and_modifiers() {
+        let class = Namespace::component("card")
+            .el("title")
+            .attr("large")
+            .state("active");
+        assert_eq!(
+            class.to_string(),
+            "c-card__title c-card__title--large is-active"
+        );
+        assert_eq!(class.rendered_len(), class.to_string().len());
+    }
+
+    #[test]
+    fn it_should_support_custom_prefixes() {
+        let class = Namespace::new("l-").classname("grid").attr("wide");
+        assert_eq!(class.to_string(), "l-grid l-grid--wide");
+        assert_eq!(
+            class.tokens().collect::<Vec<_>>(),
+            vec!["l-grid", "l-grid--wide"]
+        );
+    }
+
+    #[test]
+    fn it_should_convert_into_strings() {
+        assert_eq!(String::from(Namespace::component("card")), "c-card");
+    }
+}
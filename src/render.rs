@@ -0,0 +1,43 @@
+use ::std::fmt;
+use ::std::fmt::Write;
+
+/// A writer which only counts the bytes written to it.
+struct LenCounter {
+    len: usize,
+}
+
+impl fmt::Write for LenCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+/// Returns the exact length of the class once printed, without allocating.
+pub(crate) fn rendered_len<D: fmt::Display + ?Sized>(class: &D) -> usize {
+    let mut counter = LenCounter { len: 0 };
+    // The counter never fails, so neither can this.
+    let _ = write!(counter, "{}", class);
+    counter.len
+}
+
+/// Prints the class into a `String`, which is allocated once at the right size.
+pub(crate) fn render<D: fmt::Display + ?Sized>(class: &D) -> String {
+    let mut output = String::with_capacity(rendered_len(class));
+    let _ = write!(output, "{}", class);
+    output
+}
+
+#[cfg(test)]
+mod render_to_string {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn it_should_allocate_exactly_once() {
+        let class = classname("card").el("title").attr("large") + "bold";
+        let output = render(&class);
+        assert_eq!(output, "card__title card__title--large bold");
+        assert_eq!(output.capacity(), output.len());
+    }
+}
@@ -0,0 +1,82 @@
+use ::std::fmt;
+
+///
+/// Wraps a writer, and normalises the whitespace written through it.
+///
+/// Class tokens are separated by a single space. Leading, trailing,
+/// and repeated whitespace is dropped. This allows parts which render
+/// as empty, such as a `None` class, to be skipped over.
+///
+pub(crate) struct TokenWriter<W> {
+    inner: W,
+    has_written: bool,
+    is_space_pending: bool,
+}
+
+impl<W: fmt::Write> TokenWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            has_written: false,
+            is_space_pending: false,
+        }
+    }
+
+    fn write_part(&mut self, part: &str) -> fmt::Result {
+        if part.is_empty() {
+            return Ok(());
+        }
+
+        if self.is_space_pending && self.has_written {
+            self.inner.write_char(' ')?;
+        }
+
+        self.is_space_pending = false;
+        self.has_written = true;
+        self.inner.write_str(part)
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for TokenWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut parts = s.split(char::is_whitespace);
+
+        // The first part continues on from the token written last.
+        if let Some(part) = parts.next() {
+            self.write_part(part)?;
+        }
+
+        for part in parts {
+            self.is_space_pending = true;
+            self.write_part(part)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod write_str {
+    use super::*;
+    use ::std::fmt::Write;
+
+    #[test]
+    fn it_should_join_tokens_split_across_writes() {
+        let mut output = String::new();
+        let mut writer = TokenWriter::new(&mut output);
+        writer.write_str("card").unwrap();
+        writer.write_str("__title").unwrap();
+        writer.write_str(" bold").unwrap();
+        assert_eq!(output, "card__title bold");
+    }
+
+    #[test]
+    fn it_should_drop_leading_trailing_and_repeated_whitespace() {
+        let mut output = String::new();
+        let mut writer = TokenWriter::new(&mut output);
+        writer.write_str("  page  ").unwrap();
+        writer.write_str("").unwrap();
+        writer.write_str(" noscript ").unwrap();
+        assert_eq!(output, "page noscript");
+    }
+}
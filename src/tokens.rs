@@ -0,0 +1,38 @@
+use ::std::borrow::Cow;
+use ::std::fmt;
+
+///
+/// An iterator over the individual class names of a `Class`.
+///
+/// Names which exist as-is, such as a block name, are borrowed.
+/// Names which have to be built, such as `card__title--large`,
+/// are built one at a time.
+///
+pub struct Tokens<'a> {
+    inner: Box<dyn Iterator<Item = Cow<'a, str>> + 'a>,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new<I>(tokens: I) -> Self
+    where
+        I: Iterator<Item = Cow<'a, str>> + 'a,
+    {
+        Self {
+            inner: Box::new(tokens),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<'a> fmt::Debug for Tokens<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tokens").finish()
+    }
+}